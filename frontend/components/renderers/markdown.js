@@ -90,7 +90,7 @@ export function renderBasicMarkdown(text) {
   for (const line of lines) {
     const trimmed = line.trim()
     const isBlock = trimmed.startsWith('<h') || trimmed.startsWith('<ul>') || 
-                   trimmed.startsWith('<ol>') || trimmed.startsWith('<li>') ||
+                   trimmed.startsWith('<ol>') || trimmed.startsWith('<li') ||
                    trimmed.startsWith('<table>') || trimmed.startsWith('<blockquote>') ||
                    trimmed.startsWith('<hr') || trimmed.startsWith('<pre>') ||
                    trimmed === ''
@@ -172,7 +172,14 @@ export function processLists(html) {
         }
       }
       
-      result.push(`<li>${content}`)
+      // GitHub-style task list items, e.g. checklists in spec analysis
+      const checkbox = content.match(/^\[([ xX])\]\s+(.*)$/)
+      if (checkbox) {
+        const checked = checkbox[1] !== ' ' ? ' checked' : ''
+        result.push(`<li class="task-list-item"><input type="checkbox" disabled${checked}> ${checkbox[2]}`)
+      } else {
+        result.push(`<li>${content}`)
+      }
     } else {
       // Not a list item, close all open lists
       while (listStack.length > 0) {
@@ -23,9 +23,12 @@ export function renderSpecsTable(specs) {
   }
   
   tableBody.innerHTML = specs.map((spec, index) => {
-    const progress = spec.task_count > 0 
-      ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
-      : 0
+    // Rolled up from task checklists by scan_specs
+    const progress = spec.progress !== undefined
+      ? Math.round(spec.progress)
+      : spec.task_count > 0 
+        ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
+        : 0
     
     const statusIcon = getStatusIcon(spec.status)
     const statusClass = getStatusClass(spec.status)
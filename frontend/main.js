@@ -1,6 +1,7 @@
-import { state, bindCommon, selectProject } from './shared.js'
+import { state, bindCommon, selectProject, escapeHtml } from './shared.js'
 import { createCommandButtonGroup } from './components/ui/commandButton.js'
 import { openExecutionModal } from './components/modals/executionModal.js'
+import { renderBasicMarkdown } from './components/renderers/markdown.js'
 
 // Get invoke function from shared.js
 let invoke;
@@ -111,210 +112,6 @@ async function openFilePreview(filePath) {
   }
 }
 
-function renderBasicMarkdown(text) {
-  // Enhanced markdown rendering with tables, links, and nested lists support
-  let html = escapeHtml(text)
-  
-  // Preserve code blocks first (to avoid processing their content)
-  const codeBlocks = []
-  html = html.replace(/```([\s\S]*?)```/g, (match, code) => {
-    codeBlocks.push(`<pre><code>${code}</code></pre>`)
-    return `__CODE_BLOCK_${codeBlocks.length - 1}__`
-  })
-  
-  // Inline code (preserve these too)
-  const inlineCodes = []
-  html = html.replace(/`([^`]+)`/g, (match, code) => {
-    inlineCodes.push(`<code>${code}</code>`)
-    return `__INLINE_CODE_${inlineCodes.length - 1}__`
-  })
-  
-  // Headers (h1-h6)
-  html = html.replace(/^###### (.*?)$/gm, '<h6>$1</h6>')
-  html = html.replace(/^##### (.*?)$/gm, '<h5>$1</h5>')
-  html = html.replace(/^#### (.*?)$/gm, '<h4>$1</h4>')
-  html = html.replace(/^### (.*?)$/gm, '<h3>$1</h3>')
-  html = html.replace(/^## (.*?)$/gm, '<h2>$1</h2>')
-  html = html.replace(/^# (.*?)$/gm, '<h1>$1</h1>')
-  
-  // Links - handle both [text](url) and [text](url "title")
-  html = html.replace(/\[([^\]]+)\]\(([^)"]+)(?:\s+"([^"]+)")?\)/g, (match, text, url, title) => {
-    const titleAttr = title ? ` title="${title}"` : ''
-    return `<a href="${url}"${titleAttr} target="_blank" rel="noopener noreferrer">${text}</a>`
-  })
-  
-  // Images - ![alt](url)
-  html = html.replace(/!\[([^\]]*)\]\(([^)]+)\)/g, '<img src="$2" alt="$1" />')
-  
-  // Tables
-  html = html.replace(/(\|[^\n]+\|\n)(\|[\s:|-]+\|\n)((?:\|[^\n]+\|\n)+)/gm, (match, header, separator, body) => {
-    // Parse header
-    const headerCells = header.trim().split('|').filter(cell => cell.trim())
-    const headerRow = '<tr>' + headerCells.map(cell => `<th>${cell.trim()}</th>`).join('') + '</tr>'
-    
-    // Parse alignment from separator
-    const alignments = separator.trim().split('|').filter(cell => cell.trim()).map(cell => {
-      const trimmed = cell.trim()
-      if (trimmed.startsWith(':') && trimmed.endsWith(':')) return 'center'
-      if (trimmed.endsWith(':')) return 'right'
-      return 'left'
-    })
-    
-    // Parse body rows
-    const bodyRows = body.trim().split('\n').map(row => {
-      const cells = row.split('|').filter(cell => cell.trim())
-      return '<tr>' + cells.map((cell, i) => {
-        const align = alignments[i] || 'left'
-        return `<td style="text-align: ${align}">${cell.trim()}</td>`
-      }).join('') + '</tr>'
-    }).join('')
-    
-    return `<table><thead>${headerRow}</thead><tbody>${bodyRows}</tbody></table>`
-  })
-  
-  // Bold and italic (handle both ** and __ for bold, * and _ for italic)
-  html = html.replace(/\*\*([^*]+)\*\*/g, '<strong>$1</strong>')
-  html = html.replace(/__([^_]+)__/g, '<strong>$1</strong>')
-  html = html.replace(/\*([^*]+)\*/g, '<em>$1</em>')
-  html = html.replace(/_([^_]+)_/g, '<em>$1</em>')
-  
-  // Strikethrough
-  html = html.replace(/~~([^~]+)~~/g, '<del>$1</del>')
-  
-  // Horizontal rules
-  html = html.replace(/^---$/gm, '<hr>')
-  html = html.replace(/^\*\*\*$/gm, '<hr>')
-  
-  // Process lists (including nested lists)
-  html = processLists(html)
-  
-  // Blockquotes
-  html = html.replace(/^&gt; (.+)$/gm, '<blockquote>$1</blockquote>')
-  // Merge consecutive blockquotes
-  html = html.replace(/<\/blockquote>\n<blockquote>/g, '\n')
-  
-  // Paragraphs (don't wrap headers, lists, tables, etc.)
-  const lines = html.split('\n')
-  const processedLines = []
-  let inParagraph = false
-  
-  for (const line of lines) {
-    const trimmed = line.trim()
-    const isBlock = trimmed.startsWith('<h') || trimmed.startsWith('<ul>') || 
-                   trimmed.startsWith('<ol>') || trimmed.startsWith('<li>') ||
-                   trimmed.startsWith('<table>') || trimmed.startsWith('<blockquote>') ||
-                   trimmed.startsWith('<hr') || trimmed.startsWith('<pre>') ||
-                   trimmed === ''
-    
-    if (isBlock) {
-      if (inParagraph) {
-        processedLines.push('</p>')
-        inParagraph = false
-      }
-      processedLines.push(line)
-    } else {
-      if (!inParagraph) {
-        processedLines.push('<p>')
-        inParagraph = true
-      }
-      processedLines.push(line)
-    }
-  }
-  
-  if (inParagraph) {
-    processedLines.push('</p>')
-  }
-  
-  html = processedLines.join('\n')
-  
-  // Restore code blocks and inline codes
-  html = html.replace(/__CODE_BLOCK_(\d+)__/g, (match, index) => codeBlocks[index])
-  html = html.replace(/__INLINE_CODE_(\d+)__/g, (match, index) => inlineCodes[index])
-  
-  return html
-}
-
-function processLists(html) {
-  // Process nested lists with proper indentation
-  const lines = html.split('\n')
-  const result = []
-  const listStack = [] // Track list type and depth
-  
-  for (let i = 0; i < lines.length; i++) {
-    const line = lines[i]
-    const match = line.match(/^(\s*)([-*+]|\d+\.)\s+(.*)$/)
-    
-    if (match) {
-      const [, indent, marker, content] = match
-      const depth = Math.floor(indent.length / 2)
-      const isOrdered = /\d+\./.test(marker)
-      const listType = isOrdered ? 'ol' : 'ul'
-      
-      // Close deeper lists
-      while (listStack.length > depth + 1) {
-        const popped = listStack.pop()
-        result.push(`</${popped.type}>`)
-        if (listStack.length > 0) {
-          result.push('</li>')
-        }
-      }
-      
-      // Open new list if needed
-      if (listStack.length === depth) {
-        // Same level, close previous item if exists
-        if (listStack.length > 0 && result[result.length - 1] !== `<${listType}>`) {
-          result.push('</li>')
-        }
-        // Start new list at this level
-        result.push(`<${listType}>`)
-        listStack.push({ type: listType, depth })
-      } else if (listStack.length === depth + 1) {
-        // Correct level
-        const currentList = listStack[listStack.length - 1]
-        if (currentList.type !== listType) {
-          // Wrong list type, close and open new
-          result.push(`</${currentList.type}>`)
-          listStack.pop()
-          result.push(`<${listType}>`)
-          listStack.push({ type: listType, depth })
-        } else if (result[result.length - 1] !== `<${listType}>`) {
-          // Same type, close previous item
-          result.push('</li>')
-        }
-      }
-      
-      result.push(`<li>${content}`)
-    } else {
-      // Not a list item, close all open lists
-      while (listStack.length > 0) {
-        const popped = listStack.pop()
-        if (result[result.length - 1] !== `</${popped.type}>`) {
-          result.push('</li>')
-        }
-        result.push(`</${popped.type}>`)
-      }
-      result.push(line)
-    }
-  }
-  
-  // Close any remaining open lists
-  while (listStack.length > 0) {
-    const popped = listStack.pop()
-    if (result[result.length - 1] !== `</${popped.type}>`) {
-      result.push('</li>')
-    }
-    result.push(`</${popped.type}>`)
-  }
-  
-  return result.join('\n')
-}
-
-function escapeHtml(text) {
-  const div = document.createElement('div')
-  div.textContent = text
-  return div.innerHTML
-}
-
 function setupInstallerWizard() {
   const installerBtn = document.getElementById('btnInstaller')
   if (installerBtn) {
@@ -365,22 +162,16 @@ async function openInstallerWizard() {
             <textarea id="project-description" placeholder="Brief description of the project" rows="3"></textarea>
           </div>
           <div class="form-group">
-            <label>
-              <input type="checkbox" id="create-standards" checked />
-              Create standards directory with common templates
-            </label>
-          </div>
-          <div class="form-group">
-            <label>
-              <input type="checkbox" id="create-specs" checked />
-              Create specs directory for technical specifications
-            </label>
+            <label for="install-profile">Profile:</label>
+            <select id="install-profile">
+              <option value="full" selected>Full Agent-SDD (standards, instructions, agents and scripts)</option>
+              <option value="minimal">Minimal (instructions and scripts)</option>
+              <option value="custom">Custom (copy from a directory)</option>
+            </select>
           </div>
-          <div class="form-group">
-            <label>
-              <input type="checkbox" id="create-agents" checked />
-              Create agents directory for AI agent configurations
-            </label>
+          <div class="form-group" id="custom-profile-group" style="display: none;">
+            <button id="choose-profile-dir" class="btn-primary">Choose Profile Directory</button>
+            <div id="selected-profile-dir" class="selected-path"></div>
           </div>
         </div>
         
@@ -409,6 +200,7 @@ async function openInstallerWizard() {
   // Modal state
   let currentStep = 1
   let selectedProjectPath = ''
+  let selectedProfileDir = ''
   let projectConfig = {}
   
   // Close handlers
@@ -456,7 +248,8 @@ async function openInstallerWizard() {
       nextBtn.disabled = !selectedProjectPath
     } else if (currentStep === 2) {
       const projectName = document.getElementById('project-name').value.trim()
-      nextBtn.disabled = !projectName
+      const needsProfileDir = document.getElementById('install-profile').value === 'custom'
+      nextBtn.disabled = !projectName || (needsProfileDir && !selectedProfileDir)
     }
   }
   
@@ -484,6 +277,26 @@ async function openInstallerWizard() {
   
   // Step 2: Form validation
   document.getElementById('project-name').addEventListener('input', updateNextButtonState)
+  document.getElementById('install-profile').addEventListener('change', (e) => {
+    document.getElementById('custom-profile-group').style.display = e.target.value === 'custom' ? 'block' : 'none'
+    updateNextButtonState()
+  })
+  document.getElementById('choose-profile-dir').addEventListener('click', async () => {
+    try {
+      if (!invoke && window.__TAURI__ && window.__TAURI__.core) {
+        invoke = window.__TAURI__.core.invoke;
+      }
+      
+      const path = await invoke('select_base_dir')
+      if (path) {
+        selectedProfileDir = path
+        document.getElementById('selected-profile-dir').textContent = path
+        updateNextButtonState()
+      }
+    } catch (error) {
+      alert(`Failed to select directory: ${error.message}`)
+    }
+  })
   
   // Navigation buttons
   document.getElementById('wizard-back').addEventListener('click', () => {
@@ -500,9 +313,8 @@ async function openInstallerWizard() {
       projectConfig = {
         name: document.getElementById('project-name').value.trim(),
         description: document.getElementById('project-description').value.trim(),
-        createStandards: document.getElementById('create-standards').checked,
-        createSpecs: document.getElementById('create-specs').checked,
-        createAgents: document.getElementById('create-agents').checked
+        profile: document.getElementById('install-profile').value,
+        customDir: selectedProfileDir || null
       }
       
       // Show summary
@@ -515,11 +327,13 @@ async function openInstallerWizard() {
   
   function showInstallSummary() {
     const summary = document.getElementById('install-summary')
-    const directories = []
-    if (projectConfig.createStandards) directories.push('standards/')
-    if (projectConfig.createSpecs) directories.push('specs/')
-    if (projectConfig.createAgents) directories.push('agents/')
-    directories.push('product/', 'instructions/')
+    const profileDirectories = {
+      full: ['product/', 'specs/', 'standards/', 'instructions/', 'agents/', 'scripts/'],
+      minimal: ['product/', 'specs/', 'instructions/', 'scripts/'],
+      custom: ['product/', 'specs/', `everything in ${selectedProfileDir}`]
+    }
+    const directories = profileDirectories[projectConfig.profile] || []
+    const profileSelect = document.getElementById('install-profile')
     
     summary.innerHTML = `
       <div class="summary-item">
@@ -531,6 +345,9 @@ async function openInstallerWizard() {
       <div class="summary-item">
         <strong>Description:</strong> ${projectConfig.description || 'No description'}
       </div>
+      <div class="summary-item">
+        <strong>Profile:</strong> ${profileSelect.options[profileSelect.selectedIndex].text}
+      </div>
       <div class="summary-item">
         <strong>Directories to create:</strong>
         <ul class="directory-list">
@@ -561,13 +378,11 @@ async function openInstallerWizard() {
       const steps = [
         'Creating .agent-sdd directory...',
         'Creating product directory...',
-        'Creating instructions directory...',
-        projectConfig.createStandards ? 'Creating standards directory...' : null,
-        projectConfig.createSpecs ? 'Creating specs directory...' : null,
-        projectConfig.createAgents ? 'Creating agents directory...' : null,
-        'Creating configuration files...',
+        'Installing profile files...',
+        'Making scripts executable...',
         'Installation complete!'
-      ].filter(Boolean)
+      ]
+      let report = null
       
       for (let i = 0; i < steps.length; i++) {
         document.getElementById('progress-text').textContent = steps[i]
@@ -575,7 +390,7 @@ async function openInstallerWizard() {
         
         if (i === 0) {
           // Actually create the directories
-          await invoke('create_agent_sdd_structure', {
+          report = await invoke('create_agent_sdd_structure', {
             projectPath: selectedProjectPath,
             config: projectConfig
           })
@@ -590,6 +405,10 @@ async function openInstallerWizard() {
         <div class="install-success">
           <h4>✅ Installation Successful!</h4>
           <p>Agent-SDD structure has been created in:<br><code>${selectedProjectPath}/.agent-sdd/</code></p>
+          <p>Created ${report.directories.length} directories and ${report.files.length} files (${report.executables.length} executable scripts):</p>
+          <ul class="directory-list">
+            ${report.files.map(file => `<li>${file}${report.executables.includes(file) ? ' (executable)' : ''}</li>`).join('')}
+          </ul>
           <p>You can now close this wizard and refresh the project list to see your new project.</p>
         </div>
       `
@@ -602,7 +421,7 @@ async function openInstallerWizard() {
       resultEl.innerHTML = `
         <div class="install-error">
           <h4>❌ Installation Failed</h4>
-          <p>Error: ${error.message}</p>
+          <p>Error: ${error.message || error}</p>
         </div>
       `
       installBtn.disabled = false
@@ -1107,9 +926,12 @@ function renderSortedSpecs(sortState) {
     
     row.addEventListener('click', () => selectSpecRow(row, spec))
     
-    const progressPercent = spec.task_count > 0 
-      ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
-      : 0
+    // Rolled up from task checklists by scan_specs
+    const progressPercent = spec.progress !== undefined
+      ? Math.round(spec.progress)
+      : spec.task_count > 0 
+        ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
+        : 0
     
     const statusIcon = getStatusIcon(spec.status)
     const effortDisplay = spec.tasks.reduce((total, task) => {
@@ -1248,9 +1070,12 @@ function renderSpecDetailsPanel(spec) {
   if (!detailsPanel) return
   
   // Calculate progress metrics
-  const progressPercent = spec.task_count > 0 
-    ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
-    : 0
+  // Rolled up from task checklists by scan_specs
+  const progressPercent = spec.progress !== undefined
+    ? Math.round(spec.progress)
+    : spec.task_count > 0 
+      ? Math.round((spec.completed_tasks / spec.task_count) * 100) 
+      : 0
   
   const totalEffort = spec.tasks.reduce((sum, task) => sum + getEffortValue(task.effort), 0)
   const completedEffort = spec.tasks
@@ -1739,13 +1564,28 @@ async function markTaskAsCompleted(spec, task) {
     task.status = 'completed'
     task.completed = new Date().toISOString().split('T')[0]
     
-    await invoke('update_task_status', { 
+    const update = await invoke('update_task_status', {
       specPath: spec.path,
       taskId: task.id,
       status: 'completed',
       completedDate: task.completed
     })
     
+    // Completing the last task offers to record the spec in the roadmap
+    if (update && update.progress_log_entry) {
+      const entry = update.progress_log_entry
+      const preview = `**[${entry.date}] – ${entry.title}**\n- What: ${entry.what}\n- Why: ${entry.why}\n- Impact: ${entry.impact}`
+      if (confirm(`All tasks in this spec are completed. Add this entry to the roadmap Progress Log?\n\n${preview}`)) {
+        try {
+          await invoke('append_progress_log', { specPath: spec.path, entry })
+          await invoke('complete_roadmap_items', { specPath: spec.path })
+        } catch (logError) {
+          console.error('Failed to update roadmap:', logError)
+          alert(`Failed to update roadmap: ${logError}`)
+        }
+      }
+    }
+    
     // Refresh the specs data
     const sortState = {
       column: 'modified',
@@ -1850,10 +1690,11 @@ function openCreateSpecDialog() {
           <textarea id="spec-description-input" placeholder="Brief description of what this spec will accomplish" rows="3"></textarea>
         </div>
         <div class="form-group">
-          <label>
-            <input type="checkbox" id="lite-mode-checkbox" />
-            Lite mode (minimal template)
-          </label>
+          <label for="spec-template-select">Template:</label>
+          <select id="spec-template-select">
+            <option value="full">full - Full spec with UI requirements, theme compliance and test scenarios</option>
+            <option value="lite">lite - Minimal spec: goal, success criteria and milestones</option>
+          </select>
         </div>
       </div>
       <div class="flex justify-end gap-2 p-4 border-t border-gray-200 dark:border-gray-700">
@@ -1884,11 +1725,15 @@ function openCreateSpecDialog() {
     document.getElementById('spec-name-input').focus()
   }, 100)
   
+  // Offer the project's own templates from .agent-sdd/templates alongside the built-ins
+  loadSpecTemplateOptions()
+  
   // Create spec handler
   document.getElementById('create-spec-btn').addEventListener('click', async () => {
     const specName = document.getElementById('spec-name-input').value.trim()
     const description = document.getElementById('spec-description-input').value.trim()
-    const liteMode = document.getElementById('lite-mode-checkbox').checked
+    const template = document.getElementById('spec-template-select').value
+    const liteMode = template === 'lite'
     
     if (!specName) {
       alert('Please enter a spec name')
@@ -1928,7 +1773,8 @@ function openCreateSpecDialog() {
         projectPath,
         specName,
         description,
-        liteMode
+        liteMode,
+        template
       })
       
       // Show success message using the existing analysis results modal
@@ -1961,6 +1807,31 @@ function openCreateSpecDialog() {
   })
 }
 
+async function loadSpecTemplateOptions() {
+  const select = document.getElementById('spec-template-select')
+  const projectSelect = document.getElementById('projectsSelect')
+  if (!select || !projectSelect || !projectSelect.value) return
+  
+  if (!invoke && window.__TAURI__ && window.__TAURI__.core) {
+    invoke = window.__TAURI__.core.invoke
+  }
+  if (!invoke) return
+  
+  try {
+    const templates = await invoke('list_spec_templates', { projectPath: projectSelect.value })
+    const selected = select.value
+    select.innerHTML = templates.map(template => {
+      const label = template.source === 'project' ? `${template.name} (project)` : template.name
+      return `<option value="${escapeHtml(template.name)}">${escapeHtml(label)} - ${escapeHtml(template.description)}</option>`
+    }).join('')
+    if (templates.some(template => template.name === selected)) {
+      select.value = selected
+    }
+  } catch (error) {
+    console.error('Failed to load spec templates:', error)
+  }
+}
+
 // Export for use in shared.js
 window.openFilePreview = openFilePreview
 window.openSpecsManagementWindow = openSpecsManagementWindow
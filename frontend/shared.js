@@ -48,6 +48,11 @@ let state = {
 
 function bytesToHuman(n){ if(!n) return '0 B'; const u=['B','KB','MB','GB','TB']; let i=0; while(n>=1024&&i<u.length-1){n/=1024;i++} return `${n.toFixed(1)} ${u[i]}` }
 function timeAgo(ms){ if(!ms) return '—'; const d=Date.now()-ms; const m=Math.floor(d/60000); if(m<1) return 'just now'; if(m<60) return `${m}m ago`; const h=Math.floor(m/60); if(h<24) return `${h}h ago`; const days=Math.floor(h/24); return `${days}d ago` }
+function escapeHtml(text) {
+  const div = document.createElement('div')
+  div.textContent = text
+  return div.innerHTML
+}
 
 function renderProjectsList() {
   const select = document.getElementById('projectsSelect')
@@ -135,11 +140,146 @@ function renderSections(report) {
   if (Array.isArray(report.warnings) && report.warnings.length) {
     warn.innerHTML = report.warnings.map(w => `<div class="card" style="border-left:4px solid var(--warning)"><strong>Warning:</strong> ${w}</div>`).join('')
   }
+  if (report.has_agent_sdd && (report.warnings || []).some(w => w.includes('repair the structure'))) {
+    warn.insertAdjacentHTML('beforeend', `
+      <div class="card" style="display:flex;gap:8px;align-items:center">
+        <span>Create what's missing without touching existing files:</span>
+        <select id="repairProfile">
+          <option value="full" selected>Full Agent-SDD</option>
+          <option value="minimal">Minimal</option>
+        </select>
+        <button id="btnRepair" class="btn-primary">Repair structure</button>
+      </div>
+    `)
+    document.getElementById('btnRepair').addEventListener('click', repairStructure)
+  }
+}
+
+const CHECK_BADGES = { pass: 'ok', warn: 'warn', fail: 'danger' }
+
+async function runDoctor() {
+  if (!state.selected?.projectPath) return
+  const panel = document.getElementById('doctor')
+  panel.innerHTML = '<div class="card">Running health check...</div>'
+  try {
+    const report = await invoke('run_doctor', { projectPath: state.selected.projectPath })
+    renderDoctor(report)
+    const stale = await invoke('get_stale_tasks', { projectPath: state.selected.projectPath })
+    renderStaleTasks(stale)
+  } catch (error) {
+    panel.innerHTML = `<div class="card" style="border-left:4px solid var(--danger)"><strong>Health check failed:</strong> ${error.message || error}</div>`
+  }
+}
+
+function renderDoctor(report) {
+  const panel = document.getElementById('doctor')
+  panel.innerHTML = `
+    <div class="card">
+      <div style="display:flex;justify-content:space-between;align-items:center">
+        <strong>Health check</strong>
+        <span class="muted">${report.passed} passed • ${report.warnings} warnings • ${report.failures} failed</span>
+      </div>
+      ${report.checks.map(check => `
+        <div class="doctor-check" style="margin-top:8px">
+          <span class="badge ${CHECK_BADGES[check.status]}">${check.status}</span>
+          <strong style="margin-left:8px">${escapeHtml(check.name)}</strong>
+          <span class="muted" style="margin-left:8px">${escapeHtml(check.message)}</span>
+          ${check.remediation ? `<div class="muted" style="margin:4px 0 0 8px">→ ${escapeHtml(check.remediation)}</div>` : ''}
+          ${check.fixable ? `<button class="btn-primary doctor-fix" data-check="${check.id}" style="margin:4px 0 0 8px">Fix</button>` : ''}
+        </div>
+      `).join('')}
+    </div>
+  `
+  panel.querySelectorAll('.doctor-fix').forEach(button => {
+    button.addEventListener('click', () => applyDoctorFix(button.dataset.check, button))
+  })
+}
+
+function renderStaleTasks(report) {
+  if (!report.in_progress) return
+  const panel = document.getElementById('doctor')
+  panel.insertAdjacentHTML('beforeend', `
+    <div class="card">
+      <div style="display:flex;justify-content:space-between;align-items:center">
+        <strong>Stale tasks</strong>
+        <span class="muted">
+          ${report.tasks.length} of ${report.in_progress} in progress • threshold
+          <input id="staleAfterDays" type="number" min="1" value="${report.stale_after_days}" style="width:60px"> days
+        </span>
+      </div>
+      ${report.tasks.length ? '' : '<div class="muted" style="margin-top:8px">In-progress tasks have recent activity.</div>'}
+      ${report.tasks.map((task, index) => `
+        <div style="margin-top:8px">
+          <span class="badge warn">${escapeHtml(task.task_id)}</span>
+          <strong style="margin-left:8px">${escapeHtml(task.task_name)}</strong>
+          <span class="muted" style="margin-left:8px">${escapeHtml(task.spec_id)} • ${escapeHtml(task.reasons.join(', '))}</span>
+          <button class="btn-primary stale-reset" data-index="${index}" style="margin:4px 0 0 8px">Reset to pending</button>
+        </div>
+      `).join('')}
+    </div>
+  `)
+  panel.querySelectorAll('.stale-reset').forEach(button => {
+    button.addEventListener('click', () => resetStaleTask(report.tasks[button.dataset.index], button))
+  })
+  document.getElementById('staleAfterDays').addEventListener('change', saveStaleThreshold)
+}
+
+async function saveStaleThreshold(event) {
+  const days = parseInt(event.target.value, 10)
+  if (!(days >= 1)) return
+  try {
+    await invoke('update_settings', { patch: { stale_after_days: days } })
+    await runDoctor()
+  } catch (error) {
+    alert(`Failed to save threshold: ${error.message || error}`)
+  }
+}
+
+async function resetStaleTask(task, button) {
+  if (!confirm(`Set ${task.task_id} back to pending?`)) return
+  button.disabled = true
+  try {
+    await invoke('reset_stale_tasks', { specPath: task.spec_path, taskIds: [task.task_id] })
+    await selectProject(state.selected.projectPath, state.selected.name)
+    await runDoctor()
+  } catch (error) {
+    alert(`Reset failed: ${error.message || error}`)
+    button.disabled = false
+  }
+}
+
+async function applyDoctorFix(checkId, button) {
+  button.disabled = true
+  try {
+    await invoke('apply_doctor_fix', { projectPath: state.selected.projectPath, checkId })
+    // Fixes can change sections too, so refresh both
+    await selectProject(state.selected.projectPath, state.selected.name)
+    await runDoctor()
+  } catch (error) {
+    alert(`Fix failed: ${error.message || error}`)
+    button.disabled = false
+  }
+}
+
+async function repairStructure() {
+  const { projectPath, name } = state.selected
+  const profile = document.getElementById('repairProfile').value
+  try {
+    const result = await invoke('repair_agent_sdd_structure', { projectPath, profile })
+    const created = [...result.directories, ...result.files]
+    const lines = created.length ? created.map(p => `• ${p}`).join('\n') : 'Nothing was missing.'
+    alert(`Repaired with the ${result.profile} profile:\n${lines}\n\n${result.skipped.length} existing files left unchanged.`)
+    await selectProject(projectPath, name)
+  } catch (error) {
+    alert(`Failed to repair structure: ${error.message || error}`)
+  }
 }
 
 async function selectProject(projectPath, name) {
   document.getElementById('projectTitle').innerHTML = `<h2>${name}</h2><div class="muted">${projectPath}</div>`
   document.getElementById('btnRefresh').disabled = false
+  document.getElementById('btnDoctor').disabled = false
+  document.getElementById('doctor').innerHTML = ''
 
   const report = await invoke('scan_project', { projectPath })
   state.selected = { projectPath, name }
@@ -183,6 +323,7 @@ async function chooseBaseDir() {
 
 function bindCommon() {
   document.getElementById('btnChooseBase').addEventListener('click', chooseBaseDir)
+  document.getElementById('btnDoctor').addEventListener('click', runDoctor)
   document.getElementById('btnRefresh').addEventListener('click', async () => {
     if (!state.selected?.projectPath) return
     await selectProject(state.selected.projectPath, state.selected.name)
@@ -203,4 +344,4 @@ function bindCommon() {
   })
 }
 
-export { state, bindCommon, selectProject }
+export { state, bindCommon, selectProject, escapeHtml }
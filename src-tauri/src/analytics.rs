@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::path::Path;
+use chrono::{Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, SpecMetadata};
+use crate::git::file_revisions;
+use crate::history::read_executions;
+
+const DEFAULT_VELOCITY_WINDOW_DAYS: u32 = 14;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectAnalytics {
+    project_path: String,
+    generated: String,
+    velocity_window_days: u32,
+    project: ProgressSeries,
+    specs: Vec<SpecAnalytics>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpecAnalytics {
+    spec_id: String,
+    feature: String,
+    progress: ProgressSeries,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProgressSeries {
+    total_tasks: usize,
+    completed_tasks: usize,
+    // Completed tasks with no known completion date. They count as done before
+    // the first bucket, so `remaining` always ends at the current figure.
+    undated_completions: usize,
+    sources: CompletionSources,
+    buckets: Vec<DailyBucket>,
+    // Completed tasks per day over the velocity window
+    velocity: f64,
+    projected_completion: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CompletionSources {
+    task_field: usize,
+    git: usize,
+    execution_history: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DailyBucket {
+    date: String,
+    completed: usize,
+    cumulative_completed: usize,
+    remaining: usize,
+}
+
+// Completion dates gathered for one spec, or for a whole project
+#[derive(Default)]
+struct Completions {
+    total_tasks: usize,
+    dates: Vec<NaiveDate>,
+    undated: usize,
+    sources: CompletionSources,
+    start: Option<NaiveDate>,
+}
+
+#[tauri::command]
+pub async fn get_progress_analytics(
+    project_path: String,
+    include_git: Option<bool>,
+    velocity_window_days: Option<u32>,
+) -> Result<ProjectAnalytics, String> {
+    let project_dir = Path::new(&project_path);
+    let agent_sdd_dir = project_dir.join(".agent-sdd");
+    if !agent_sdd_dir.exists() || !agent_sdd_dir.is_dir() {
+        return Err("Project does not contain .agent-sdd directory".to_string());
+    }
+    
+    let include_git = include_git.unwrap_or(false);
+    let window = velocity_window_days.unwrap_or(DEFAULT_VELOCITY_WINDOW_DAYS).max(1);
+    let today = Utc::now().date_naive();
+    
+    // Latest successful task execution per (spec, task), used when nothing better is known
+    let mut executed: HashMap<(String, String), NaiveDate> = HashMap::new();
+    for record in read_executions(&agent_sdd_dir) {
+        if !record.success || record.command != "sdd-execute-task" {
+            continue;
+        }
+        if let Some(date) = parse_day(&record.timestamp) {
+            let entry = executed.entry((record.spec_id, record.task_id)).or_insert(date);
+            *entry = (*entry).max(date);
+        }
+    }
+    
+    let specs = scan_specs(project_path.clone(), None).await?;
+    
+    // Walking the history spawns git, so keep it off the async runtime
+    let mut git_dates: HashMap<String, HashMap<String, NaiveDate>> = if include_git {
+        let (project_dir, specs) = (project_dir.to_path_buf(), specs.clone());
+        tokio::task::spawn_blocking(move || {
+            specs.iter()
+                .map(|spec| (spec.id.clone(), git_completion_dates(&project_dir, spec)))
+                .collect()
+        })
+        .await
+        .map_err(|e| format!("Failed to read git history: {}", e))?
+    } else {
+        HashMap::new()
+    };
+    
+    let mut project = Completions::default();
+    let mut spec_analytics = Vec::new();
+    
+    for spec in &specs {
+        let git_dates = git_dates.remove(&spec.id).unwrap_or_default();
+        let completions = collect_completions(spec, &git_dates, &executed);
+        
+        project.total_tasks += completions.total_tasks;
+        project.dates.extend(completions.dates.iter().copied());
+        project.undated += completions.undated;
+        project.sources.task_field += completions.sources.task_field;
+        project.sources.git += completions.sources.git;
+        project.sources.execution_history += completions.sources.execution_history;
+        project.start = match (project.start, completions.start) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        
+        spec_analytics.push(SpecAnalytics {
+            spec_id: spec.id.clone(),
+            feature: spec.feature.clone(),
+            progress: build_series(completions, today, window),
+        });
+    }
+    
+    Ok(ProjectAnalytics {
+        project_path,
+        generated: today.format("%Y-%m-%d").to_string(),
+        velocity_window_days: window,
+        project: build_series(project, today, window),
+        specs: spec_analytics,
+    })
+}
+
+// Picks a completion date for every completed task, preferring the task's own
+// `completed` field, then the first commit where git saw it completed, then the
+// last successful execution of the task
+fn collect_completions(
+    spec: &SpecMetadata,
+    git_dates: &HashMap<String, NaiveDate>,
+    executed: &HashMap<(String, String), NaiveDate>,
+) -> Completions {
+    let mut completions = Completions {
+        total_tasks: spec.tasks.len(),
+        start: parse_day(&spec.created),
+        ..Completions::default()
+    };
+    
+    for task in spec.tasks.iter().filter(|task| task.status == "completed") {
+        let date = if let Some(date) = task.completed.as_deref().and_then(parse_day) {
+            completions.sources.task_field += 1;
+            Some(date)
+        } else if let Some(date) = git_dates.get(&task.id) {
+            completions.sources.git += 1;
+            Some(*date)
+        } else if let Some(date) = executed.get(&(spec.id.clone(), task.id.clone())) {
+            completions.sources.execution_history += 1;
+            Some(*date)
+        } else {
+            None
+        };
+        
+        match date {
+            Some(date) => {
+                completions.start = Some(completions.start.map_or(date, |start| start.min(date)));
+                completions.dates.push(date);
+            }
+            None => completions.undated += 1,
+        }
+    }
+    
+    completions
+}
+
+fn build_series(completions: Completions, today: NaiveDate, window: u32) -> ProgressSeries {
+    let start = completions.start.unwrap_or(today).min(today);
+    let completed_tasks = completions.dates.len() + completions.undated;
+    
+    let mut per_day: HashMap<NaiveDate, usize> = HashMap::new();
+    for date in &completions.dates {
+        *per_day.entry(*date).or_insert(0) += 1;
+    }
+    
+    let mut buckets = Vec::new();
+    let mut cumulative = 0;
+    let mut day = start;
+    while day <= today {
+        let completed = per_day.get(&day).copied().unwrap_or(0);
+        cumulative += completed;
+        buckets.push(DailyBucket {
+            date: day.format("%Y-%m-%d").to_string(),
+            completed,
+            cumulative_completed: cumulative,
+            remaining: completions.total_tasks.saturating_sub(cumulative + completions.undated),
+        });
+        day += Duration::days(1);
+    }
+    
+    let window_start = today - Duration::days(window as i64 - 1);
+    let recent = completions.dates.iter().filter(|date| **date >= window_start).count();
+    let velocity = recent as f64 / window as f64;
+    
+    let remaining = completions.total_tasks.saturating_sub(completed_tasks);
+    let projected_completion = if remaining > 0 && velocity > 0.0 {
+        let days = (remaining as f64 / velocity).ceil() as i64;
+        Some((today + Duration::days(days)).format("%Y-%m-%d").to_string())
+    } else {
+        None
+    };
+    
+    ProgressSeries {
+        total_tasks: completions.total_tasks,
+        completed_tasks,
+        undated_completions: completions.undated,
+        sources: completions.sources,
+        buckets,
+        velocity,
+        projected_completion,
+    }
+}
+
+// First commit date at which each task appears as completed in tasks.json
+fn git_completion_dates(project_dir: &Path, spec: &SpecMetadata) -> HashMap<String, NaiveDate> {
+    let mut dates = HashMap::new();
+    let tasks_file = Path::new(&spec.path).join("tasks.json");
+    
+    for (date, content) in file_revisions(project_dir, &tasks_file) {
+        let tasks_json: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(json) => json,
+            Err(_) => continue,
+        };
+        
+        for task in tasks_json["tasks"].as_array().into_iter().flatten() {
+            if task["status"].as_str() == Some("completed") {
+                if let Some(id) = task["id"].as_str() {
+                    dates.entry(id.to_string()).or_insert(date);
+                }
+            }
+        }
+    }
+    
+    dates
+}
+
+// Accepts plain dates as well as full timestamps
+pub(crate) fn parse_day(value: &str) -> Option<NaiveDate> {
+    value.get(..10).and_then(|day| NaiveDate::parse_from_str(day, "%Y-%m-%d").ok())
+}
@@ -0,0 +1,481 @@
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::commands::scaffold_spec;
+use crate::tasks::TaskRecord;
+use crate::templates::DEFAULT_TEMPLATE;
+
+// Phrases that introduce a task's dependencies, longest first
+const DEPENDENCY_PHRASES: [&str; 3] = ["dependent on ", "depends on ", "depend on "];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BriefSpec {
+    spec_path: String,
+    tasks: Vec<TaskRecord>,
+    // Parts of the brief that couldn't be used as written
+    warnings: Vec<String>,
+}
+
+// What a brief says, split by section, before it becomes a spec
+#[derive(Debug, Default)]
+struct Brief {
+    title: Option<String>,
+    description: Vec<String>,
+    user_story: Vec<String>,
+    acceptance_criteria: Vec<String>,
+    tasks: Vec<BriefTask>,
+    // Sections the brief has beyond the ones above, carried over as written
+    sections: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, Default)]
+struct BriefTask {
+    text: String,
+    done: bool,
+    // Indented lines under the bullet
+    details: Vec<String>,
+    // Nested bullets
+    checklist: Vec<(String, bool)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Description,
+    UserStory,
+    Criteria,
+    Tasks,
+    Other,
+}
+
+// Creates a spec from a markdown brief such as an issue body. The first H1
+// names the spec unless `spec_name` is given; "User Story", "Acceptance
+// Criteria" and "Tasks" sections fill the matching parts of sdd.md and
+// tasks.json, and other sections are carried into sdd.md as written. Each
+// top-level bullet under Tasks becomes a task, its nested bullets a checklist,
+// and "depends on X" in its text a dependency.
+#[tauri::command]
+pub async fn create_spec_from_brief(
+    project_path: String,
+    brief: String,
+    spec_name: Option<String>,
+    template: Option<String>,
+) -> Result<BriefSpec, String> {
+    let parsed = parse_brief(&brief);
+    let spec_name = spec_name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| parsed.title.clone())
+        .ok_or("The brief has no '# Title' heading, so the spec needs a name")?;
+    
+    let mut warnings = Vec::new();
+    let tasks = if parsed.tasks.is_empty() {
+        warnings.push("The brief has no Tasks section; the template's default tasks were used".to_string());
+        None
+    } else {
+        Some(brief_tasks(&parsed.tasks, &mut warnings))
+    };
+    
+    let user_story = parsed.user_story.iter()
+        .map(|line| strip_bullet(line).trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let description = match block_text(&parsed.description) {
+        description if !description.is_empty() => description,
+        _ if !user_story.is_empty() => user_story.clone(),
+        _ => spec_name.clone(),
+    };
+    let sections: Vec<Value> = parsed.sections.iter()
+        .map(|(title, lines)| serde_json::json!({ "title": title, "body": block_text(lines) }))
+        .collect();
+    
+    let mut context = Map::new();
+    context.insert("description".to_string(), Value::String(description));
+    context.insert("user_story".to_string(), Value::String(user_story));
+    context.insert("acceptance_criteria".to_string(), serde_json::json!(parsed.acceptance_criteria));
+    context.insert("sections".to_string(), Value::Array(sections));
+    
+    let template_name = template
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string());
+    let (spec_dir, tasks_file) = scaffold_spec(Path::new(&project_path), &spec_name, &template_name, context, tasks)?;
+    
+    Ok(BriefSpec {
+        spec_path: spec_dir.to_string_lossy().to_string(),
+        tasks: tasks_file.tasks,
+        warnings,
+    })
+}
+
+fn parse_brief(content: &str) -> Brief {
+    let mut brief = Brief::default();
+    let mut section = Section::Description;
+    let mut in_fence = false;
+    // Indent of the bullets that start tasks, set by the first one
+    let mut task_indent: Option<usize> = None;
+    
+    for line in content.lines() {
+        let mut trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        }
+        
+        let mut line = line;
+        if !in_fence {
+            if let Some((level, title)) = heading(trimmed) {
+                if level == 1 && brief.title.is_none() {
+                    brief.title = Some(title.to_string());
+                    section = Section::Description;
+                    continue;
+                }
+                section = classify_section(title);
+                if section == Section::Other {
+                    brief.sections.push((title.to_string(), Vec::new()));
+                }
+                task_indent = None;
+                continue;
+            }
+            
+            // `**User Story:** As a user...` switches section and keeps the rest of the line
+            if let Some((label, rest)) = inline_label(trimmed) {
+                section = classify_section(label);
+                task_indent = None;
+                if rest.is_empty() {
+                    continue;
+                }
+                line = rest;
+                trimmed = rest;
+            }
+        }
+        
+        match section {
+            Section::Description => brief.description.push(line.to_string()),
+            Section::UserStory => brief.user_story.push(line.to_string()),
+            Section::Other => {
+                if let Some((_, lines)) = brief.sections.last_mut() {
+                    lines.push(line.to_string());
+                }
+            }
+            Section::Criteria => {
+                let text = strip_checkbox(strip_bullet(trimmed)).1;
+                // Indented lines that aren't bullets continue the previous criterion
+                let continuation = bullet(line).is_none() && indent_of(line) > 0;
+                if text.is_empty() {
+                    continue;
+                }
+                match brief.acceptance_criteria.last_mut() {
+                    Some(last) if continuation => {
+                        last.push(' ');
+                        last.push_str(text);
+                    }
+                    _ => brief.acceptance_criteria.push(text.to_string()),
+                }
+            }
+            Section::Tasks => match bullet(line) {
+                Some((indent, content)) if task_indent.map_or(true, |base| indent <= base) => {
+                    task_indent = Some(indent);
+                    let (done, text) = strip_checkbox(content);
+                    brief.tasks.push(BriefTask { text: text.to_string(), done, ..BriefTask::default() });
+                }
+                Some((_, content)) => {
+                    if let Some(task) = brief.tasks.last_mut() {
+                        let (done, text) = strip_checkbox(content);
+                        task.checklist.push((text.to_string(), done));
+                    }
+                }
+                None if !trimmed.is_empty() && indent_of(line) > 0 => {
+                    if let Some(task) = brief.tasks.last_mut() {
+                        task.details.push(trimmed.to_string());
+                    }
+                }
+                None => {}
+            },
+        }
+    }
+    
+    brief
+}
+
+// Turns parsed task bullets into task records: explicit ids are kept, the rest
+// numbered TASK-001 onwards around them, then dependency references resolved
+// against the list
+fn brief_tasks(tasks: &[BriefTask], warnings: &mut Vec<String>) -> Vec<TaskRecord> {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let mut references = Vec::new();
+    let mut records: Vec<TaskRecord> = Vec::new();
+    
+    for task in tasks {
+        let (id, text) = explicit_id(&task.text);
+        let (text, refs) = extract_dependencies(text);
+        let (name, mut description) = split_name(&text);
+        for detail in &task.details {
+            if !description.is_empty() {
+                description.push(' ');
+            }
+            description.push_str(detail);
+        }
+        
+        let mut extra = Map::new();
+        if !task.checklist.is_empty() {
+            let items = task.checklist.iter()
+                .map(|(text, done)| serde_json::json!({ "text": text, "done": done }))
+                .collect();
+            extra.insert("checklist".to_string(), Value::Array(items));
+        }
+        
+        records.push(TaskRecord {
+            id: id.unwrap_or_default(),
+            name,
+            description,
+            status: if task.done { "completed" } else { "pending" }.to_string(),
+            completed: task.done.then(|| today.clone()),
+            dependencies: Vec::new(),
+            effort: String::new(),
+            ux_ui_reviewed: None,
+            extra,
+            present_fields: Vec::new(),
+        });
+        references.push(refs);
+    }
+    
+    let mut number = 0;
+    for index in 0..records.len() {
+        while records[index].id.is_empty() {
+            number += 1;
+            let id = format!("TASK-{:03}", number);
+            if !records.iter().any(|task| task.id == id) {
+                records[index].id = id;
+            }
+        }
+    }
+    
+    for (index, refs) in references.into_iter().enumerate() {
+        for reference in refs {
+            match resolve_reference(&reference, &records) {
+                Some(dep) if dep == records[index].id => {
+                    warnings.push(format!("Task {} depends on itself; dropped", records[index].id));
+                }
+                Some(dep) => {
+                    if !records[index].dependencies.contains(&dep) {
+                        records[index].dependencies.push(dep);
+                    }
+                }
+                None => warnings.push(format!(
+                    "Task {} depends on '{}', which is not a task in the brief; dropped",
+                    records[index].id,
+                    reference
+                )),
+            }
+        }
+    }
+    
+    records
+}
+
+// `# Title` headings, and bold or colon-terminated labels on a line of their
+// own when they name a known section
+fn heading(line: &str) -> Option<(usize, &str)> {
+    if line.starts_with('#') {
+        let level = line.chars().take_while(|c| *c == '#').count();
+        if !line[level..].starts_with(' ') {
+            return None;
+        }
+        let title = line[level..].trim().trim_end_matches('#').trim();
+        return (!title.is_empty()).then_some((level, title));
+    }
+    
+    let label = line.strip_prefix("**")
+        .and_then(|rest| rest.strip_suffix("**").or_else(|| rest.strip_suffix("**:")))
+        .or_else(|| line.strip_suffix(':'))?
+        .trim()
+        .trim_end_matches(':');
+    (classify_section(label) != Section::Other).then_some((2, label))
+}
+
+// `**Label:** text` with a known section label
+fn inline_label(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix("**")?;
+    let (label, text) = rest.split_once("**")?;
+    let label = label.trim().trim_end_matches(':');
+    let text = text.trim_start_matches(':').trim();
+    (classify_section(label) != Section::Other).then_some((label, text))
+}
+
+fn classify_section(title: &str) -> Section {
+    let title = title.to_lowercase();
+    let title = title.trim().trim_end_matches(':');
+    if title.contains("user stor") || title == "story" {
+        Section::UserStory
+    } else if title.contains("acceptance") || title.contains("success criteria") || title == "criteria" || title == "definition of done" {
+        Section::Criteria
+    } else if title == "tasks" || title == "task list" || title == "todo" || title == "to do" || title.contains("implementation plan") {
+        Section::Tasks
+    } else if ["overview", "description", "summary", "goal", "background", "context", "problem"].contains(&title) {
+        Section::Description
+    } else {
+        Section::Other
+    }
+}
+
+// Indent and text of a `-`, `*`, `+` or `1.` list item
+fn bullet(line: &str) -> Option<(usize, &str)> {
+    let indent = indent_of(line);
+    let rest = line.trim_start();
+    let marker_len = if rest.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || !rest[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        digits + 1
+    };
+    
+    let text = &rest[marker_len..];
+    if !text.starts_with([' ', '\t']) {
+        return None;
+    }
+    Some((indent, text.trim()))
+}
+
+fn strip_bullet(line: &str) -> &str {
+    bullet(line).map_or(line.trim(), |(_, text)| text)
+}
+
+fn strip_checkbox(text: &str) -> (bool, &str) {
+    if let Some(rest) = text.strip_prefix("[ ]") {
+        (false, rest.trim())
+    } else if let Some(rest) = text.strip_prefix("[x]").or_else(|| text.strip_prefix("[X]")) {
+        (true, rest.trim())
+    } else {
+        (false, text)
+    }
+}
+
+fn indent_of(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+// Lines of a section as markdown, without leading and trailing blank lines
+fn block_text(lines: &[String]) -> String {
+    lines.join("\n").trim().to_string()
+}
+
+// A leading `TASK-001:`, `**TASK-001**` or `[TASK-001]` id
+fn explicit_id(text: &str) -> (Option<String>, &str) {
+    let rest = text.trim_start_matches(['*', '[']);
+    let end = rest.find(|c: char| c.is_whitespace() || matches!(c, ':' | ']' | '*')).unwrap_or(rest.len());
+    let candidate = &rest[..end];
+    
+    let is_id = candidate.rsplit_once('-').is_some_and(|(prefix, number)| {
+        !prefix.is_empty()
+            && prefix.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+    });
+    if !is_id {
+        return (None, text);
+    }
+    
+    let remainder = rest[end..].trim_start_matches(['*', ']', ':', ' ', '-', '—']);
+    (Some(candidate.to_string()), remainder)
+}
+
+// Pulls every "depends on X, Y and Z" out of a task's text. Returns the text
+// without them and the references in the order written.
+fn extract_dependencies(text: &str) -> (String, Vec<String>) {
+    let mut text = text.to_string();
+    let mut references = Vec::new();
+    
+    loop {
+        // ASCII lowercasing keeps byte offsets lined up with `text`
+        let lower = text.to_ascii_lowercase();
+        let found = DEPENDENCY_PHRASES.iter()
+            .filter_map(|phrase| {
+                let start = lower.find(phrase)?;
+                let at_word = lower[..start].chars().last().map_or(true, |c| !c.is_alphanumeric());
+                at_word.then_some((start, phrase.len()))
+            })
+            .min_by_key(|(start, _)| *start);
+        let (start, phrase_len) = match found {
+            Some(found) => found,
+            None => break,
+        };
+        
+        let after = start + phrase_len;
+        let in_parens = text[..start].trim_end().ends_with('(');
+        let end = if in_parens {
+            text[after..].find(')').map_or(text.len(), |i| after + i)
+        } else {
+            [";", ")", ". "].iter()
+                .filter_map(|stop| text[after..].find(stop))
+                .min()
+                .map_or(text.len(), |i| after + i)
+        };
+        
+        for part in text[after..end].split(',') {
+            for reference in part.split(" and ").flat_map(|part| part.split(" & ")) {
+                let reference = reference.trim().trim_end_matches('.');
+                if !reference.is_empty() {
+                    references.push(reference.to_string());
+                }
+            }
+        }
+        
+        let (cut_start, cut_end) = if in_parens {
+            (text[..start].rfind('(').unwrap_or(start), (end + 1).min(text.len()))
+        } else {
+            (start, end)
+        };
+        let before = text[..cut_start].trim_end().trim_end_matches([',', ';', '-', '—']).trim_end();
+        let rest = text[cut_end..].trim_start();
+        text = if before.is_empty() || rest.is_empty() || rest.starts_with(['.', ',', ';']) {
+            format!("{}{}", before, rest)
+        } else {
+            format!("{} {}", before, rest)
+        };
+    }
+    
+    (text.trim().to_string(), references)
+}
+
+// "Name: description", "Name - description" or just a name
+fn split_name(text: &str) -> (String, String) {
+    let split = [": ", " - ", " — "].iter()
+        .filter_map(|separator| text.find(separator).map(|i| (i, separator.len())))
+        .min_by_key(|(i, _)| *i);
+    let (name, description) = match split {
+        Some((i, len)) => (&text[..i], &text[i + len..]),
+        None => (text, ""),
+    };
+    (name.trim().trim_matches('*').trim().to_string(), description.trim().to_string())
+}
+
+// A dependency reference as a task id: an id, a position in the list ("2" or
+// "task 2"), a task name, or a `spec/TASK-ID` reference to another spec
+fn resolve_reference(reference: &str, tasks: &[TaskRecord]) -> Option<String> {
+    let mut reference = reference.trim().trim_matches(['`', '"', '\'', '*']).trim();
+    for prefix in ["tasks ", "task "] {
+        if reference.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)) {
+            reference = reference[prefix.len()..].trim();
+        }
+    }
+    let reference = reference.trim_start_matches('#');
+    
+    if reference.contains('/') {
+        return Some(reference.to_string());
+    }
+    if let Some(task) = tasks.iter().find(|task| task.id.eq_ignore_ascii_case(reference)) {
+        return Some(task.id.clone());
+    }
+    if let Ok(position) = reference.parse::<usize>() {
+        return tasks.get(position.checked_sub(1)?).map(|task| task.id.clone());
+    }
+    tasks.iter()
+        .find(|task| task.name.eq_ignore_ascii_case(reference))
+        .map(|task| task.id.clone())
+}
@@ -1,15 +1,37 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use tauri_plugin_dialog::DialogExt;
 use tokio::time::timeout;
 
+use crate::dependencies::{build_dependency_graph, project_specs, resolve_dependency, spec_dependency_problems, split_dependency};
+use crate::doctor::REQUIRED_DIRECTORIES;
+use crate::history::record_execution;
+use crate::lifecycle::status_warnings;
+use crate::roadmap::{link_spec, load_roadmap};
+use crate::settings::{remember_base_dir, SettingsStore};
+use crate::specs::ARCHIVE_DIR;
+use crate::stale::{find_stale_tasks, stale_threshold};
+use crate::tasks::{parse_checklist, save_tasks_file, task_progress, validate_tasks, ChecklistItem, TaskRecord, TasksFile};
+use crate::templates::{load_spec_template, DEFAULT_TEMPLATE, LITE_TEMPLATE};
+
+// Commands `execute_agent_sdd_command` runs, each through `.agent-sdd/scripts/<command>.sh`
+pub(crate) const AGENT_SDD_COMMANDS: [&str; 6] = [
+    "sdd-execute-task",
+    "sdd-fix",
+    "sdd-tweak",
+    "sdd-check-task",
+    "sdd-queue-fix",
+    "sdd-queue-tweak",
+];
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DirectoryInfo {
-    name: String,
-    full_path: String,
+    pub(crate) name: String,
+    pub(crate) full_path: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -35,18 +57,34 @@ pub struct SectionSummary {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileInfo {
-    rel_path: String,
-    full_path: String,
-    size: u64,
-    mtime: Option<u64>,
+    pub(crate) rel_path: String,
+    pub(crate) full_path: String,
+    pub(crate) size: u64,
+    pub(crate) mtime: Option<u64>,
 }
 
 #[tauri::command]
-pub async fn select_base_dir(app: tauri::AppHandle) -> Result<Option<String>, String> {
-    let dialog = app.dialog().file();
+pub async fn select_base_dir(app: tauri::AppHandle, settings: tauri::State<'_, SettingsStore>) -> Result<Option<String>, String> {
+    let mut dialog = app.dialog().file();
+    
+    // Start from the most recently used base directory
+    match settings.read(&app) {
+        Ok(current) => {
+            if let Some(last) = current.recent_base_dirs().first() {
+                dialog = dialog.set_directory(last);
+            }
+        }
+        Err(e) => log::warn!("Failed to read settings: {}", e),
+    }
     
     match dialog.blocking_pick_folder() {
-        Some(folder) => Ok(Some(folder.to_string())),
+        Some(folder) => {
+            let folder = folder.to_string();
+            if let Err(e) = remember_base_dir(&app, &settings, &folder) {
+                log::warn!("Failed to remember base directory: {}", e);
+            }
+            Ok(Some(folder))
+        }
         None => Ok(None),
     }
 }
@@ -123,8 +161,18 @@ pub async fn scan_project(project_path: String) -> Result<ProjectReport, String>
         for section_name in section_names {
             let section_path = agent_sdd_dir.join(section_name);
             let section_info = scan_section(&section_path, section_name).await?;
+            // Standards and agents only come with the full profile, so their
+            // absence isn't something to repair
+            if !section_info.exists && REQUIRED_DIRECTORIES.contains(&section_name) {
+                warnings.push(format!("Missing section: .agent-sdd/{} (repair the structure to create it)", section_name));
+            }
             sections.insert(section_name.to_string(), section_info);
         }
+        
+        // Commands run the scripts in here, so without it nothing can execute
+        if !agent_sdd_dir.join("scripts").is_dir() {
+            warnings.push("Missing .agent-sdd/scripts: commands can't be executed (repair the structure to install the standard scripts)".to_string());
+        }
     } else {
         let exists = agent_sdd_dir.exists();
         let is_dir = agent_sdd_dir.is_dir();
@@ -226,7 +274,7 @@ async fn scan_section(section_path: &Path, section_name: &str) -> Result<Section
     })
 }
 
-fn scan_directory_recursive(dir_path: &Path, base_path: &Path) -> Result<Vec<FileInfo>, String> {
+pub(crate) fn scan_directory_recursive(dir_path: &Path, base_path: &Path) -> Result<Vec<FileInfo>, String> {
     let mut files = Vec::new();
     
     match fs::read_dir(dir_path) {
@@ -282,48 +330,57 @@ fn scan_directory_recursive(dir_path: &Path, base_path: &Path) -> Result<Vec<Fil
     Ok(files)
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SpecMetadata {
-    id: String,
-    name: String,
-    feature: String,
-    phase: String,
-    status: String,
-    created: String,
-    path: String,
-    task_count: usize,
-    completed_tasks: usize,
-    size_bytes: u64,
-    last_modified: Option<u64>,
-    tasks: Vec<TaskInfo>,
+// Writes to a sibling temp file first so readers never see a half-written file
+pub(crate) fn write_file_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let file_name = path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or("Invalid file path")?;
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+    
+    fs::write(&temp_path, content)
+        .map_err(|e| format!("Failed to write {}: {}", temp_path.display(), e))?;
+    fs::rename(&temp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to replace {}: {}", path.display(), e)
+    })
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TaskInfo {
-    id: String,
-    name: String,
-    description: String,
-    status: String,
-    completed: Option<String>,
-    dependencies: Vec<String>,
-    effort: String,
-    ux_ui_reviewed: Option<bool>,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecMetadata {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) feature: String,
+    pub(crate) phase: String,
+    pub(crate) status: String,
+    pub(crate) created: String,
+    pub(crate) path: String,
+    pub(crate) task_count: usize,
+    pub(crate) completed_tasks: usize,
+    // Percent done, counting ticked checklist items of unfinished tasks
+    #[serde(default)]
+    pub(crate) progress: f64,
+    pub(crate) size_bytes: u64,
+    pub(crate) last_modified: Option<u64>,
+    pub(crate) tasks: Vec<TaskInfo>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ProjectConfig {
-    name: String,
-    description: String,
-    #[serde(rename = "createStandards")]
-    create_standards: bool,
-    #[serde(rename = "createSpecs")]
-    create_specs: bool,
-    #[serde(rename = "createAgents")]
-    create_agents: bool,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskInfo {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) status: String,
+    pub(crate) completed: Option<String>,
+    pub(crate) dependencies: Vec<String>,
+    pub(crate) effort: String,
+    pub(crate) ux_ui_reviewed: Option<bool>,
+    #[serde(default)]
+    pub(crate) checklist: Vec<ChecklistItem>,
 }
 
+// Archived specs live in `specs/_archive/` and are left out unless asked for
 #[tauri::command]
-pub async fn scan_specs(project_path: String) -> Result<Vec<SpecMetadata>, String> {
+pub async fn scan_specs(project_path: String, include_archived: Option<bool>) -> Result<Vec<SpecMetadata>, String> {
     let project_dir = Path::new(&project_path);
     let specs_dir = project_dir.join(".agent-sdd").join("specs");
     
@@ -332,8 +389,21 @@ pub async fn scan_specs(project_path: String) -> Result<Vec<SpecMetadata>, Strin
     }
     
     let mut specs = Vec::new();
+    scan_specs_in(&specs_dir, &mut specs).await?;
+    
+    let archive_dir = specs_dir.join(ARCHIVE_DIR);
+    if include_archived.unwrap_or(false) && archive_dir.is_dir() {
+        scan_specs_in(&archive_dir, &mut specs).await?;
+    }
+    
+    // Sort by creation date (newest first)
+    specs.sort_by(|a, b| b.created.cmp(&a.created));
     
-    match fs::read_dir(&specs_dir) {
+    Ok(specs)
+}
+
+async fn scan_specs_in(dir: &Path, specs: &mut Vec<SpecMetadata>) -> Result<(), String> {
+    match fs::read_dir(dir) {
         Ok(entries) => {
             for entry in entries {
                 match entry {
@@ -350,19 +420,13 @@ pub async fn scan_specs(project_path: String) -> Result<Vec<SpecMetadata>, Strin
                     }
                 }
             }
+            Ok(())
         }
-        Err(e) => {
-            return Err(format!("Failed to read specs directory: {}", e));
-        }
+        Err(e) => Err(format!("Failed to read specs directory: {}", e)),
     }
-    
-    // Sort by creation date (newest first)
-    specs.sort_by(|a, b| b.created.cmp(&a.created));
-    
-    Ok(specs)
 }
 
-async fn scan_spec_directory(spec_path: &Path) -> Option<SpecMetadata> {
+pub(crate) async fn scan_spec_directory(spec_path: &Path) -> Option<SpecMetadata> {
     let tasks_file = spec_path.join("tasks.json");
     
     if !tasks_file.exists() {
@@ -416,10 +480,18 @@ async fn scan_spec_directory(spec_path: &Path) -> Option<SpecMetadata> {
                 .unwrap_or_default(),
             effort: task["effort"].as_str().unwrap_or("").to_string(),
             ux_ui_reviewed: task["ux_ui_reviewed"].as_bool(),
+            checklist: parse_checklist(task),
         };
         tasks.push(task_info);
     }
     
+    let progress = if tasks.is_empty() {
+        0.0
+    } else {
+        let done: f64 = tasks.iter().map(|task| task_progress(&task.status, &task.checklist)).sum();
+        done / tasks.len() as f64 * 100.0
+    };
+    
     // Calculate directory size and last modified time
     let (size_bytes, last_modified) = calculate_directory_stats(spec_path);
     
@@ -439,13 +511,14 @@ async fn scan_spec_directory(spec_path: &Path) -> Option<SpecMetadata> {
         path: spec_path.to_string_lossy().to_string(),
         task_count,
         completed_tasks,
+        progress,
         size_bytes,
         last_modified,
         tasks,
     })
 }
 
-fn calculate_directory_stats(dir_path: &Path) -> (u64, Option<u64>) {
+pub(crate) fn calculate_directory_stats(dir_path: &Path) -> (u64, Option<u64>) {
     let mut total_size = 0u64;
     let mut latest_mtime: Option<u64> = None;
     
@@ -477,107 +550,35 @@ fn calculate_directory_stats(dir_path: &Path) -> (u64, Option<u64>) {
 }
 
 #[tauri::command]
-pub async fn create_agent_sdd_structure(project_path: String, config: ProjectConfig) -> Result<String, String> {
-    let project_dir = Path::new(&project_path);
-    
-    if !project_dir.exists() || !project_dir.is_dir() {
-        return Err("Project path does not exist or is not a directory".to_string());
-    }
-    
-    let agent_sdd_dir = project_dir.join(".agent-sdd");
-    
-    // Check if .agent-sdd already exists
-    if agent_sdd_dir.exists() {
-        return Err("Agent-SDD structure already exists in this directory".to_string());
-    }
-    
-    // Create .agent-sdd directory
-    fs::create_dir(&agent_sdd_dir)
-        .map_err(|e| format!("Failed to create .agent-sdd directory: {}", e))?;
-    
-    // Create core directories
-    let core_dirs = ["product", "instructions"];
-    for dir_name in core_dirs {
-        let dir_path = agent_sdd_dir.join(dir_name);
-        fs::create_dir(&dir_path)
-            .map_err(|e| format!("Failed to create {} directory: {}", dir_name, e))?;
-    }
-    
-    // Create optional directories
-    if config.create_standards {
-        let standards_dir = agent_sdd_dir.join("standards");
-        fs::create_dir(&standards_dir)
-            .map_err(|e| format!("Failed to create standards directory: {}", e))?;
-            
-        // Create a basic standards template
-        let standards_readme = standards_dir.join("README.md");
-        let standards_content = "# Standards\n\nThis directory contains project standards and guidelines.\n\n## Code Standards\n- Follow consistent naming conventions\n- Write self-documenting code\n- Include appropriate comments\n\n## Documentation Standards\n- Keep documentation up to date\n- Use clear and concise language\n- Include examples where helpful\n";
-        fs::write(&standards_readme, standards_content)
-            .map_err(|e| format!("Failed to create standards README: {}", e))?;
-    }
-    
-    if config.create_specs {
-        let specs_dir = agent_sdd_dir.join("specs");
-        fs::create_dir(&specs_dir)
-            .map_err(|e| format!("Failed to create specs directory: {}", e))?;
-            
-        // Create a basic specs template
-        let specs_readme = specs_dir.join("README.md");
-        let specs_content = "# Technical Specifications\n\nThis directory contains detailed technical specifications for the project.\n\n## Structure\n- `api/` - API specifications and documentation\n- `database/` - Database schemas and migration plans\n- `architecture/` - System architecture documents\n- `requirements/` - Functional and non-functional requirements\n";
-        fs::write(&specs_readme, specs_content)
-            .map_err(|e| format!("Failed to create specs README: {}", e))?;
-    }
-    
-    if config.create_agents {
-        let agents_dir = agent_sdd_dir.join("agents");
-        fs::create_dir(&agents_dir)
-            .map_err(|e| format!("Failed to create agents directory: {}", e))?;
-            
-        // Create a basic agents template
-        let agents_readme = agents_dir.join("README.md");
-        let agents_content = "# AI Agents\n\nThis directory contains AI agent configurations and prompts.\n\n## Structure\n- `prompts/` - Reusable prompts for various tasks\n- `workflows/` - Multi-step agent workflows\n- `configs/` - Agent-specific configuration files\n";
-        fs::write(&agents_readme, agents_content)
-            .map_err(|e| format!("Failed to create agents README: {}", e))?;
-    }
-    
-    // Create product overview file
-    let product_dir = agent_sdd_dir.join("product");
-    let overview_file = product_dir.join("overview.md");
-    let overview_content = format!(
-        "# {}\n\n{}\n\n## Project Overview\n\nThis is an Agent-SDD enabled project.\n\n## Key Features\n\n- Feature 1\n- Feature 2\n- Feature 3\n\n## Getting Started\n\n1. Review the project documentation\n2. Set up your development environment\n3. Run the application\n\n## Resources\n\n- [Agent-SDD Documentation](https://github.com/agent-sdd)\n- [Project Repository](./)\n",
-        config.name,
-        if config.description.is_empty() { 
-            "Project description coming soon." 
-        } else { 
-            &config.description 
-        }
-    );
-    fs::write(&overview_file, overview_content)
-        .map_err(|e| format!("Failed to create product overview: {}", e))?;
-    
-    // Create roadmap file
-    let roadmap_file = product_dir.join("roadmap.md");
-    let roadmap_content = format!(
-        "# {} Roadmap\n\n## Phase 1: Foundation\n- [ ] Set up project structure\n- [ ] Implement core functionality\n- [ ] Create initial documentation\n\n## Phase 2: Features\n- [ ] Add key features\n- [ ] Implement user interface\n- [ ] Add testing framework\n\n## Phase 3: Polish\n- [ ] Performance optimization\n- [ ] Bug fixes and improvements\n- [ ] Final documentation review\n\n## Progress Log\n\n**[{}] – Project Initialized**\n- **What:** Created Agent-SDD structure\n- **Why:** Establish organized project foundation\n- **Impact:** Enables systematic development and documentation\n",
-        config.name,
-        chrono::Utc::now().format("%Y-%m-%d")
-    );
-    fs::write(&roadmap_file, roadmap_content)
-        .map_err(|e| format!("Failed to create roadmap: {}", e))?;
-    
-    // Create basic instructions file
-    let instructions_dir = agent_sdd_dir.join("instructions");
-    let instructions_file = instructions_dir.join("development.md");
-    let instructions_content = "# Development Instructions\n\n## Setup\n\n1. Clone the repository\n2. Install dependencies\n3. Configure environment variables\n4. Run initial setup scripts\n\n## Development Workflow\n\n1. Create feature branch\n2. Make changes\n3. Test thoroughly\n4. Submit pull request\n5. Code review and merge\n\n## Testing\n\n- Run unit tests: `npm test`\n- Run integration tests: `npm run test:integration`\n- Check code coverage: `npm run coverage`\n\n## Deployment\n\n1. Ensure all tests pass\n2. Update version numbers\n3. Create release notes\n4. Deploy to staging\n5. Deploy to production\n";
-    fs::write(&instructions_file, instructions_content)
-        .map_err(|e| format!("Failed to create development instructions: {}", e))?;
+pub async fn create_spec(
+    project_path: String,
+    spec_name: String,
+    description: String,
+    lite_mode: bool,
+    template: Option<String>,
+) -> Result<String, String> {
+    let template_name = template
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| if lite_mode { LITE_TEMPLATE } else { DEFAULT_TEMPLATE }.to_string());
+    let mut context = Map::new();
+    context.insert("description".to_string(), Value::String(description));
+    context.insert("lite".to_string(), Value::Bool(lite_mode));
+    
+    let (spec_dir, _) = scaffold_spec(Path::new(&project_path), &spec_name, &template_name, context, None)?;
     
-    Ok("Agent-SDD structure created successfully".to_string())
+    Ok(format!("Spec '{}' created successfully at {}", spec_name, spec_dir.to_string_lossy()))
 }
 
-#[tauri::command]
-pub async fn create_spec(project_path: String, spec_name: String, description: String, lite_mode: bool) -> Result<String, String> {
-    let project_dir = Path::new(&project_path);
+// Creates `.agent-sdd/specs/<date>-<name>/` from a template. The template sees
+// `spec_name`, `date` and `spec_id` on top of `context`; `tasks` replaces the
+// task list from its tasks.json. Returns the new directory and its tasks.
+pub(crate) fn scaffold_spec(
+    project_dir: &Path,
+    spec_name: &str,
+    template_name: &str,
+    mut context: Map<String, Value>,
+    tasks: Option<Vec<TaskRecord>>,
+) -> Result<(PathBuf, TasksFile), String> {
     let specs_dir = project_dir.join(".agent-sdd").join("specs");
     
     if !specs_dir.exists() {
@@ -586,48 +587,62 @@ pub async fn create_spec(project_path: String, spec_name: String, description: S
     }
     
     // Generate directory name with current date
-    let date_str = chrono::Utc::now().format("%Y-%m-%d");
-    let kebab_name = spec_name.to_lowercase()
-        .split_whitespace()
-        .take(5)
-        .collect::<Vec<_>>()
-        .join("-")
-        .chars()
-        .filter(|c| c.is_alphanumeric() || *c == '-')
-        .collect::<String>();
-    
-    let spec_dir_name = format!("{}-{}", date_str, kebab_name);
+    let date_str = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let spec_dir_name = format!("{}-{}", date_str, kebab_spec_name(spec_name));
     let spec_dir = specs_dir.join(&spec_dir_name);
     
     if spec_dir.exists() {
         return Err("A spec with this name already exists for today".to_string());
     }
     
+    // Render before creating anything so a broken template leaves no empty spec behind
+    context.insert("spec_name".to_string(), Value::String(spec_name.to_string()));
+    context.insert("date".to_string(), Value::String(date_str.clone()));
+    context.insert("spec_id".to_string(), Value::String(spec_dir_name));
+    let context = Value::Object(context);
+    
+    let template = load_spec_template(project_dir, template_name)?;
+    let mut tasks_file = template.render_tasks(&context)?;
+    if let Some(tasks) = tasks {
+        tasks_file.tasks = tasks;
+    }
+    tasks_file.feature.get_or_insert_with(|| spec_name.to_string());
+    tasks_file.created.get_or_insert_with(|| date_str.clone());
+    
+    let errors = validate_tasks(&tasks_file.tasks);
+    if !errors.is_empty() {
+        return Err(format!("Spec '{}' would have invalid tasks (template '{}'):\n{}", spec_name, template.name(), errors.join("\n")));
+    }
+    let sdd_content = template.render_sdd(&context, &tasks_file)?;
+    
     fs::create_dir(&spec_dir)
         .map_err(|e| format!("Failed to create spec directory: {}", e))?;
     
-    // Create SDD markdown file
-    let sdd_content = if lite_mode {
-        create_lite_sdd_content(&spec_name, &description)
-    } else {
-        create_full_sdd_content(&spec_name, &description)
-    };
-    
     let sdd_file = spec_dir.join("sdd.md");
     fs::write(&sdd_file, sdd_content)
         .map_err(|e| format!("Failed to create SDD file: {}", e))?;
+    save_tasks_file(&spec_dir, &tasks_file)?;
     
-    // Create tasks.json
-    let tasks_content = create_tasks_json(&spec_name, &date_str.to_string());
-    let tasks_file = spec_dir.join("tasks.json");
-    fs::write(&tasks_file, tasks_content)
-        .map_err(|e| format!("Failed to create tasks file: {}", e))?;
-    
-    Ok(format!("Spec '{}' created successfully at {}", spec_name, spec_dir.to_string_lossy()))
+    Ok((spec_dir, tasks_file))
 }
 
+// `stale_after_days` overrides the stale task threshold from the app settings
 #[tauri::command]
-pub async fn analyze_spec(spec_path: String) -> Result<String, String> {
+pub async fn analyze_spec(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    spec_path: String,
+    stale_after_days: Option<u32>,
+) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path).parent()
+        .ok_or("Invalid spec path")?;
+    let specs = project_specs(spec_dir).await?;
+    spec_analysis(&spec_path, &specs, stale_threshold(&app, &settings, stale_after_days)).await
+}
+
+// Markdown analysis of the spec whose tasks.json is at `spec_path`. `specs` are
+// the project's specs, archived ones included, for cross-spec dependencies.
+pub(crate) async fn spec_analysis(spec_path: &str, specs: &[SpecMetadata], stale_after_days: u32) -> Result<String, String> {
     let spec_dir = Path::new(&spec_path).parent()
         .ok_or("Invalid spec path")?;
     let project_dir = spec_dir.ancestors()
@@ -679,6 +694,18 @@ pub async fn analyze_spec(spec_path: String) -> Result<String, String> {
             if total_tasks > 0 { (completed_tasks as f32 / total_tasks as f32) * 100.0 } else { 0.0 }));
         analysis.push_str(&format!("- **In Progress:** {}\n", in_progress_tasks));
         analysis.push_str(&format!("- **Pending:** {}\n", pending_tasks));
+        
+        let checklists: Vec<(&str, Vec<ChecklistItem>)> = tasks_array.iter()
+            .map(|task| (task["status"].as_str().unwrap_or("pending"), parse_checklist(task)))
+            .collect();
+        let items: Vec<&ChecklistItem> = checklists.iter().flat_map(|(_, checklist)| checklist).collect();
+        if !items.is_empty() {
+            let progress: f64 = checklists.iter().map(|(status, checklist)| task_progress(status, checklist)).sum();
+            analysis.push_str(&format!("- **Checklist Items:** {}/{} ticked\n", 
+                items.iter().filter(|item| item.done).count(), items.len()));
+            analysis.push_str(&format!("- **Overall Progress:** {:.1}% (counting checklists of unfinished tasks)\n", 
+                progress / total_tasks as f64 * 100.0));
+        }
         analysis.push_str("\n");
         
         // Show next task
@@ -689,23 +716,87 @@ pub async fn analyze_spec(spec_path: String) -> Result<String, String> {
             analysis.push_str(&format!("- **Name:** {}\n", next_task["name"].as_str().unwrap_or("N/A")));
             analysis.push_str(&format!("- **Description:** {}\n", next_task["description"].as_str().unwrap_or("N/A")));
             analysis.push_str(&format!("- **Effort:** {}\n", next_task["effort"].as_str().unwrap_or("N/A")));
+            let checklist = parse_checklist(next_task);
+            if !checklist.is_empty() {
+                analysis.push_str("- **Checklist:**\n");
+                for item in &checklist {
+                    analysis.push_str(&format!("  - [{}] {}\n", if item.done { "x" } else { " " }, item.text));
+                }
+            }
             analysis.push_str("\n");
         }
     }
     
+    // Check dependencies on other specs' tasks, and any that don't resolve
+    if let Some(spec) = specs.iter().find(|spec| Path::new(&spec.path) == spec_dir) {
+        let (unresolved, cycles) = spec_dependency_problems(&build_dependency_graph(specs), &spec.id);
+        let mut lines = Vec::new();
+        for task in &spec.tasks {
+            for dep in task.dependencies.iter().filter(|dep| split_dependency(dep).0.is_some()) {
+                if let Ok((dep_spec, dep_task)) = resolve_dependency(specs, spec, dep) {
+                    lines.push(if dep_task.status == "completed" {
+                        format!("- ✅ {} depends on {}: {} (completed)\n", task.id, dep, dep_task.name)
+                    } else {
+                        format!("- ⏳ {} is waiting on {}: {} ({} in {})\n", task.id, dep, dep_task.name, dep_task.status, dep_spec.feature)
+                    });
+                }
+            }
+        }
+        lines.extend(unresolved.iter().map(|dep| format!("- ⚠️ Unresolved: {}\n", dep.describe())));
+        lines.extend(cycles.iter().map(|cycle| format!("- ⚠️ Circular dependency: {}\n", cycle.join(" -> "))));
+        
+        if !lines.is_empty() {
+            analysis.push_str("## Dependencies\n");
+            for line in &lines {
+                analysis.push_str(line);
+            }
+            analysis.push('\n');
+        }
+        
+        let stale = find_stale_tasks(project_dir, std::slice::from_ref(spec), stale_after_days);
+        if !stale.is_empty() {
+            analysis.push_str("## Stale Tasks\n");
+            for task in &stale {
+                analysis.push_str(&format!("- ⚠️ {}: {} ({})\n", task.task_id, task.task_name, task.reasons.join(", ")));
+            }
+            analysis.push_str("- Reset these to pending if nobody is working on them\n");
+            analysis.push('\n');
+        }
+    }
+    
     // Check roadmap alignment
-    let roadmap_file = project_dir.join(".agent-sdd").join("product").join("roadmap.md");
-    if roadmap_file.exists() {
-        analysis.push_str("## Roadmap Alignment\n");
-        analysis.push_str("✅ Spec is part of tracked project roadmap\n\n");
-    } else {
-        analysis.push_str("## Roadmap Alignment\n");
-        analysis.push_str("⚠️ No roadmap.md found - consider creating one\n\n");
+    analysis.push_str("## Roadmap Alignment\n");
+    match (load_roadmap(project_dir)?, scan_spec_directory(spec_dir).await) {
+        (Some(roadmap), Some(spec)) => {
+            let link = link_spec(&roadmap, &spec);
+            let spec_done = spec.task_count > 0 && spec.completed_tasks == spec.task_count;
+            for item in &link.items {
+                analysis.push_str(&format!("✅ Linked to roadmap item: {} ({})\n", item.text, item.phase));
+                if spec_done && !item.checked {
+                    analysis.push_str("  - ⚠️ All tasks are completed but this roadmap item is not ticked\n");
+                }
+            }
+            if link.items.is_empty() {
+                match &link.phase {
+                    Some(phase) => analysis.push_str(&format!(
+                        "⚠️ Spec matches roadmap phase '{}' but no roadmap item - mention the spec id or feature name in an item\n",
+                        phase
+                    )),
+                    None => analysis.push_str("⚠️ Spec is not linked to any roadmap item or phase\n"),
+                }
+            }
+            analysis.push('\n');
+        }
+        (Some(_), None) => analysis.push_str("⚠️ Could not read spec to check roadmap alignment\n\n"),
+        (None, _) => analysis.push_str("⚠️ No roadmap.md found - consider creating one\n\n"),
     }
     
     analysis.push_str("## Recommendations\n");
-    if status == "pending" {
-        analysis.push_str("- Consider updating spec status to 'in_progress' when work begins\n");
+    let task_statuses: Vec<&str> = tasks_json["tasks"].as_array()
+        .map(|tasks| tasks.iter().map(|task| task["status"].as_str().unwrap_or("pending")).collect())
+        .unwrap_or_default();
+    for warning in status_warnings(status, &task_statuses) {
+        analysis.push_str(&format!("- ⚠️ {}\n", warning));
     }
     if status == "completed" {
         analysis.push_str("- ✅ Spec is complete! Review lessons learned for future specs\n");
@@ -717,150 +808,16 @@ pub async fn analyze_spec(spec_path: String) -> Result<String, String> {
     Ok(analysis)
 }
 
-fn create_lite_sdd_content(spec_name: &str, description: &str) -> String {
-    format!(r#"# {spec_name}
-
-## Overview
-
-**Goal:** {description}
-
-**User Story:** As a user, I want {description} so that I can achieve my objectives effectively.
-
-**Success Criteria:**
-- [ ] Core functionality is implemented
-- [ ] User interface is intuitive and responsive
-- [ ] All acceptance tests pass
-
-## Tasks
-
-Tasks are defined in tasks.json with detailed breakdown, dependencies, and effort estimates.
-
-Key milestones:
-1. Setup and planning
-2. Core implementation  
-3. Testing and refinement
-4. Documentation and deployment
-
-## Next Steps
-
-Review tasks.json for detailed task breakdown and begin with the first pending task.
-"#, spec_name = spec_name, description = description)
-}
-
-fn create_full_sdd_content(spec_name: &str, description: &str) -> String {
-    format!(r#"# {spec_name}
-
-## Overview
-
-**Goal:** {description}
-
-**User Story:** As a user, I want {description} so that I can achieve my objectives effectively.
-
-**Success Criteria:**
-- [ ] Core functionality is implemented
-- [ ] User interface meets design requirements
-- [ ] Performance meets specified benchmarks
-- [ ] All acceptance tests pass
-- [ ] Documentation is complete
-
-## Technical Specifications
-
-### UI Requirements
-
-**Layout:**
-- Clean, intuitive interface following established design patterns
-- Responsive design supporting desktop and mobile viewports
-- Consistent styling with theme standards
-
-**Components:**
-- Main interface components with clear hierarchy
-- Interactive elements with appropriate feedback
-- Error handling and loading states
-
-**User Experience:**
-- Intuitive navigation and workflow
-- Clear call-to-action buttons
-- Helpful error messages and guidance
-
-### Theme Standards Compliance
-
-This feature will adhere to the theme standards defined in .agent-sdd/standards/theme-standards.md:
-- Color palette consistency
-- Typography standards
-- Spacing and layout guidelines
-- Accessibility requirements
-
-## Tasks
-
-Detailed task breakdown is provided in tasks.json including:
-- Task IDs and dependencies
-- Effort estimates (XS=1 day, S=2-3 days, M=1 week)
-- Implementation order and prerequisites
-
-## Test Scenarios
-
-**Unit Tests:**
-- [ ] Core logic functions
-- [ ] Edge cases and error conditions
-- [ ] Input validation
-
-**Integration Tests:**
-- [ ] Component interactions
-- [ ] API integrations
-- [ ] Database operations
-
-**User Acceptance Tests:**
-- [ ] End-to-end user workflows
-- [ ] Cross-browser compatibility
-- [ ] Accessibility compliance
-
-## Implementation Notes
-
-Additional technical details and decisions will be documented as implementation progresses.
-"#, spec_name = spec_name, description = description)
-}
-
-fn create_tasks_json(spec_name: &str, date: &str) -> String {
-    format!(r#"{{
-  "phase": "Phase 1",
-  "feature": "{}",
-  "status": "pending",
-  "created": "{}",
-  "tasks": [
-    {{
-      "id": "TASK-001",
-      "name": "Setup component structure",
-      "description": "Create main component structure and basic layout",
-      "status": "pending",
-      "dependencies": [],
-      "effort": "XS"
-    }},
-    {{
-      "id": "TASK-002",
-      "name": "Implement core functionality",
-      "description": "Add primary business logic and core features",
-      "status": "pending",
-      "dependencies": ["TASK-001"],
-      "effort": "M"
-    }},
-    {{
-      "id": "TASK-003",
-      "name": "Add user interface",
-      "description": "Create user interface components and styling",
-      "status": "pending",
-      "dependencies": ["TASK-001"],
-      "effort": "S"
-    }},
-    {{
-      "id": "TASK-004",
-      "name": "Integration testing",
-      "description": "Test component integration and user workflows",
-      "status": "pending",
-      "dependencies": ["TASK-002", "TASK-003"],
-      "effort": "S"
-    }}
-  ]
-}}"#, spec_name, date)
+// Directory-name form of a spec name: up to 5 lowercase words joined by dashes
+pub(crate) fn kebab_spec_name(spec_name: &str) -> String {
+    spec_name.to_lowercase()
+        .split_whitespace()
+        .take(5)
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-')
+        .collect::<String>()
 }
 
 // Command execution structures and functions
@@ -890,16 +847,7 @@ pub async fn execute_agent_sdd_command(request: CommandRequest) -> Result<Comman
     log::info!("Executing Agent-SDD command: {} for task: {}", request.command, request.task_id);
     
     // Validate the command is allowed
-    let allowed_commands = vec![
-        "sdd-execute-task",
-        "sdd-fix", 
-        "sdd-tweak",
-        "sdd-check-task",
-        "sdd-queue-fix",
-        "sdd-queue-tweak"
-    ];
-    
-    if !allowed_commands.contains(&request.command.as_str()) {
+    if !AGENT_SDD_COMMANDS.contains(&request.command.as_str()) {
         return Err(format!("Command '{}' is not allowed", request.command));
     }
     
@@ -930,7 +878,12 @@ pub async fn execute_agent_sdd_command(request: CommandRequest) -> Result<Comman
     };
     
     // Execute the script with the task ID
-    execute_script_command(&script_path, &request, start_time).await
+    let result = execute_script_command(&script_path, &request, start_time).await;
+    if let Ok(command_result) = &result {
+        record_execution(&agent_sdd_dir, &request, command_result);
+    }
+    
+    result
 }
 
 async fn find_agent_sdd_script(command: &str, agent_sdd_dir: &Path) -> Option<String> {
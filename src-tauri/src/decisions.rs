@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, write_file_atomic};
+use crate::roadmap::spec_slug;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Decision {
+    // DEC-XXX id, when the entry has one
+    id: Option<String>,
+    date: String,
+    title: String,
+    status: Option<String>,
+    category: Option<String>,
+    decision: String,
+    rationale: String,
+    implementation: Vec<String>,
+    trade_offs: Vec<String>,
+    // Any other labelled fields, e.g. Alternatives or Files
+    other: BTreeMap<String, Vec<String>>,
+    linked_specs: Vec<String>,
+    line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewDecision {
+    title: String,
+    decision: String,
+    rationale: String,
+    #[serde(default)]
+    implementation: Vec<String>,
+    #[serde(default)]
+    trade_offs: Vec<String>,
+    // Spec ids the decision relates to, written into the entry so it links back
+    #[serde(default)]
+    specs: Vec<String>,
+    // Defaults to today
+    date: Option<String>,
+}
+
+#[tauri::command]
+pub async fn list_decisions(project_path: String) -> Result<Vec<Decision>, String> {
+    let path = decisions_path(Path::new(&project_path));
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read decisions.md: {}", e))?;
+    let mut decisions = parse_decisions(&content);
+    
+    let spec_ids: Vec<String> = scan_specs(project_path, Some(true)).await?
+        .into_iter()
+        .map(|spec| spec.id)
+        .collect();
+    for decision in &mut decisions {
+        decision.linked_specs = linked_specs(decision, &spec_ids);
+    }
+    
+    Ok(decisions)
+}
+
+#[tauri::command]
+pub async fn add_decision(project_path: String, decision: NewDecision) -> Result<Decision, String> {
+    if decision.title.trim().is_empty() {
+        return Err("Decision title is required".to_string());
+    }
+    
+    let path = decisions_path(Path::new(&project_path));
+    let content = if path.exists() {
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read decisions.md: {}", e))?
+    } else {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create product directory: {}", e))?;
+        }
+        "# Decisions\n".to_string()
+    };
+    
+    let date = decision.date.clone()
+        .unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d").to_string());
+    let entry = format_decision(&date, &decision);
+    
+    let mut updated = content.trim_end().to_string();
+    updated.push_str("\n\n");
+    updated.push_str(&entry);
+    write_file_atomic(&path, &updated)?;
+    
+    // Hand back the entry as it will be read from now on
+    let offset = content.trim_end().lines().count() + 1;
+    let mut added = parse_decisions(&entry).into_iter().next().ok_or("Failed to parse new decision")?;
+    added.line += offset;
+    added.linked_specs = decision.specs;
+    Ok(added)
+}
+
+fn decisions_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".agent-sdd").join("product").join("decisions.md")
+}
+
+fn format_decision(date: &str, decision: &NewDecision) -> String {
+    let mut entry = format!("## {}: {}\n", date, decision.title.trim());
+    entry.push_str(&format!("- **Decision**: {}\n", decision.decision.trim()));
+    entry.push_str(&format!("- **Rationale**: {}\n", decision.rationale.trim()));
+    
+    for (label, items) in [("Implementation", &decision.implementation), ("Trade-offs", &decision.trade_offs)] {
+        if items.is_empty() {
+            continue;
+        }
+        entry.push_str(&format!("- **{}**:\n", label));
+        for item in items {
+            entry.push_str(&format!("  - {}\n", item.trim()));
+        }
+    }
+    if !decision.specs.is_empty() {
+        entry.push_str(&format!("- **Specs**: {}\n", decision.specs.join(", ")));
+    }
+    
+    entry
+}
+
+// Reads entries in the `## YYYY-MM-DD: Title` form with `- **Label**: value`
+// fields, and the older `- YYYY-MM-DD: Title (DEC-001, Accepted, Technical)`
+// form with indented `- Label: value` fields that the theme script appends
+pub(crate) fn parse_decisions(content: &str) -> Vec<Decision> {
+    let mut decisions: Vec<Decision> = Vec::new();
+    // Fields of the current decision in order, with their lines of content
+    let mut fields: Vec<(String, Vec<String>)> = Vec::new();
+    let mut field_indent: Option<usize> = None;
+    
+    for (index, raw) in content.lines().enumerate() {
+        let trimmed = raw.trim();
+        let indent = raw.len() - raw.trim_start().len();
+        
+        let header = if let Some(heading) = trimmed.strip_prefix("##") {
+            dated_title(heading.trim_start_matches('#').trim())
+        } else if indent == 0 {
+            trimmed.strip_prefix("- ").and_then(dated_title)
+        } else {
+            None
+        };
+        
+        if let Some((date, title)) = header {
+            if let Some(decision) = decisions.last_mut() {
+                apply_fields(decision, std::mem::take(&mut fields));
+            }
+            decisions.push(new_decision(date, title, index + 1));
+            field_indent = None;
+            continue;
+        }
+        
+        if trimmed.starts_with('#') {
+            // Any other heading ends the current entry
+            if let Some(decision) = decisions.last_mut() {
+                apply_fields(decision, std::mem::take(&mut fields));
+            }
+            field_indent = None;
+            continue;
+        }
+        if decisions.is_empty() || trimmed.is_empty() {
+            continue;
+        }
+        
+        let bullet = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* "));
+        let field = bullet.and_then(|text| {
+            let (label, value) = field_label(text)?;
+            // Plain `Label: value` bullets only count at the entry's field level
+            let at_field_level = field_indent.map_or(true, |level| indent <= level);
+            (text.starts_with("**") || at_field_level).then_some((label, value))
+        });
+        
+        match field {
+            Some((label, value)) => {
+                field_indent.get_or_insert(indent);
+                let lines = if value.is_empty() { Vec::new() } else { vec![value.to_string()] };
+                fields.push((label, lines));
+            }
+            None => {
+                if let Some((_, lines)) = fields.last_mut() {
+                    lines.push(bullet.unwrap_or(trimmed).trim().to_string());
+                }
+            }
+        }
+    }
+    
+    if let Some(decision) = decisions.last_mut() {
+        apply_fields(decision, fields);
+    }
+    
+    decisions
+}
+
+// Splits "2025-08-18: Title" into its date and title
+fn dated_title(text: &str) -> Option<(&str, &str)> {
+    let (date, title) = text.split_once(':')?;
+    let date = date.trim();
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some((date, title.trim()))
+}
+
+fn new_decision(date: &str, title: &str, line: usize) -> Decision {
+    let mut decision = Decision {
+        date: date.to_string(),
+        title: title.to_string(),
+        line,
+        ..Decision::default()
+    };
+    
+    // Trailing "(DEC-001, Accepted, Technical)"
+    if let Some(open) = title.rfind('(').filter(|_| title.ends_with(')')) {
+        let parts: Vec<&str> = title[open + 1..title.len() - 1].split(',').map(str::trim).collect();
+        if parts.first().is_some_and(|part| part.starts_with("DEC-")) {
+            decision.title = title[..open].trim().to_string();
+            decision.id = parts.first().map(|s| s.to_string());
+            decision.status = parts.get(1).map(|s| s.to_string());
+            decision.category = parts.get(2).map(|s| s.to_string());
+        }
+    }
+    
+    decision
+}
+
+// Recognizes `**Label**: value`, `**Label:** value` and `Label: value`
+fn field_label(text: &str) -> Option<(String, &str)> {
+    let (label, value) = if let Some(rest) = text.strip_prefix("**") {
+        let (label, value) = rest.split_once("**")?;
+        let label = label.trim_end_matches(':');
+        (label, value.trim_start_matches(':'))
+    } else {
+        let (label, value) = text.split_once(':')?;
+        // Long prefixes are sentences that happen to contain a colon
+        if label.split_whitespace().count() > 3 {
+            return None;
+        }
+        (label, value)
+    };
+    
+    let label = label.trim();
+    if label.is_empty() {
+        return None;
+    }
+    Some((label.to_string(), value.trim()))
+}
+
+fn apply_fields(decision: &mut Decision, fields: Vec<(String, Vec<String>)>) {
+    for (label, lines) in fields {
+        match label.to_lowercase().replace([' ', '-', '_'], "").as_str() {
+            "decision" => decision.decision = lines.join(" "),
+            "rationale" => decision.rationale = lines.join(" "),
+            "implementation" => decision.implementation = lines,
+            "tradeoffs" => decision.trade_offs = lines,
+            _ => {
+                decision.other.insert(label, lines);
+            }
+        }
+    }
+}
+
+// Specs whose id, or id without the date prefix, appears anywhere in the entry
+fn linked_specs(decision: &Decision, spec_ids: &[String]) -> Vec<String> {
+    let mut text = vec![decision.title.clone(), decision.decision.clone(), decision.rationale.clone()];
+    text.extend(decision.implementation.iter().cloned());
+    text.extend(decision.trade_offs.iter().cloned());
+    text.extend(decision.other.values().flatten().cloned());
+    let text = text.join("\n").to_lowercase();
+    
+    spec_ids.iter()
+        .filter(|id| {
+            let slug = spec_slug(id).to_lowercase();
+            text.contains(&id.to_lowercase()) || (slug.contains('-') && text.contains(&slug))
+        })
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, SpecMetadata, TaskInfo};
+use crate::roadmap::{project_root, spec_slug};
+
+// Every task in a project and what it depends on, with cross-spec references
+// like `2025-08-18-auth/TASK-002` resolved. Edges point from a task to the
+// task it depends on.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    nodes: Vec<DependencyNode>,
+    edges: Vec<DependencyEdge>,
+    unresolved: Vec<UnresolvedDependency>,
+    // Each cycle as qualified ids, starting and ending with the same task
+    cycles: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyNode {
+    // `spec-id/TASK-ID`
+    id: String,
+    spec_id: String,
+    task_id: String,
+    name: String,
+    status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyEdge {
+    from: String,
+    to: String,
+    cross_spec: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnresolvedDependency {
+    // Qualified id of the task declaring the dependency
+    task: String,
+    // The dependency as written in tasks.json
+    dependency: String,
+    reason: String,
+}
+
+impl DependencyGraph {
+    pub(crate) fn problems(&self) -> (&[UnresolvedDependency], &[Vec<String>]) {
+        (&self.unresolved, &self.cycles)
+    }
+}
+
+impl UnresolvedDependency {
+    pub(crate) fn describe(&self) -> String {
+        format!("{} depends on '{}': {}", self.task, self.dependency, self.reason)
+    }
+}
+
+#[tauri::command]
+pub async fn get_dependency_graph(project_path: String) -> Result<DependencyGraph, String> {
+    let specs = scan_specs(project_path, Some(true)).await?;
+    Ok(build_dependency_graph(&specs))
+}
+
+pub(crate) fn build_dependency_graph(specs: &[SpecMetadata]) -> DependencyGraph {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut unresolved = Vec::new();
+    
+    for spec in specs {
+        for task in &spec.tasks {
+            let id = qualified_id(&spec.id, &task.id);
+            for dep in &task.dependencies {
+                match resolve_dependency(specs, spec, dep) {
+                    Ok((dep_spec, dep_task)) => edges.push(DependencyEdge {
+                        from: id.clone(),
+                        to: qualified_id(&dep_spec.id, &dep_task.id),
+                        cross_spec: dep_spec.id != spec.id,
+                    }),
+                    Err(reason) => unresolved.push(UnresolvedDependency {
+                        task: id.clone(),
+                        dependency: dep.clone(),
+                        reason,
+                    }),
+                }
+            }
+            nodes.push(DependencyNode {
+                id,
+                spec_id: spec.id.clone(),
+                task_id: task.id.clone(),
+                name: task.name.clone(),
+                status: task.status.clone(),
+            });
+        }
+    }
+    
+    let cycles = find_cycles(&nodes, &edges);
+    DependencyGraph { nodes, edges, unresolved, cycles }
+}
+
+// Splits `2025-08-18-auth/TASK-002` into its spec and task parts; bare ids have
+// no spec part
+pub(crate) fn split_dependency(dep: &str) -> (Option<&str>, &str) {
+    match dep.rsplit_once('/') {
+        Some((spec, task)) => (Some(spec.trim()), task.trim()),
+        None => (None, dep.trim()),
+    }
+}
+
+// Finds the task a dependency refers to. Bare ids are looked up in the
+// declaring spec; qualified ones in the spec named by its directory, or by the
+// name without the date when only one spec has that name.
+pub(crate) fn resolve_dependency<'a>(
+    specs: &'a [SpecMetadata],
+    from_spec: &'a SpecMetadata,
+    dep: &str,
+) -> Result<(&'a SpecMetadata, &'a TaskInfo), String> {
+    let (spec_ref, task_id) = split_dependency(dep);
+    let spec = match spec_ref {
+        None => from_spec,
+        Some(spec_ref) => find_spec(specs, spec_ref)?,
+    };
+    
+    spec.tasks.iter()
+        .find(|task| task.id == task_id)
+        .map(|task| (spec, task))
+        .ok_or_else(|| format!("Spec '{}' has no task '{}'", spec.id, task_id))
+}
+
+pub(crate) fn find_spec<'a>(specs: &'a [SpecMetadata], spec_ref: &str) -> Result<&'a SpecMetadata, String> {
+    if let Some(spec) = specs.iter().find(|spec| spec.id == spec_ref) {
+        return Ok(spec);
+    }
+    
+    let matches: Vec<&SpecMetadata> = specs.iter()
+        .filter(|spec| spec_slug(&spec.id) == spec_ref)
+        .collect();
+    match matches.as_slice() {
+        [spec] => Ok(spec),
+        [] => Err(format!("No spec named '{}'", spec_ref)),
+        _ => Err(format!(
+            "Spec name '{}' is ambiguous ({}) - use the full directory name",
+            spec_ref,
+            matches.iter().map(|spec| spec.id.as_str()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+pub(crate) fn qualified_id(spec_id: &str, task_id: &str) -> String {
+    format!("{}/{}", spec_id, task_id)
+}
+
+// Unresolved dependencies and cycles that involve one spec's tasks
+pub(crate) fn spec_dependency_problems(graph: &DependencyGraph, spec_id: &str) -> (Vec<UnresolvedDependency>, Vec<Vec<String>>) {
+    let prefix = format!("{}/", spec_id);
+    let unresolved = graph.unresolved.iter()
+        .filter(|dep| dep.task.starts_with(&prefix))
+        .cloned()
+        .collect();
+    let cycles = graph.cycles.iter()
+        .filter(|cycle| cycle.iter().any(|id| id.starts_with(&prefix)))
+        .cloned()
+        .collect();
+    (unresolved, cycles)
+}
+
+// Walks dependencies depth first, recording a cycle each time the walk gets
+// back to a task on its current path
+fn find_cycles(nodes: &[DependencyNode], edges: &[DependencyEdge]) -> Vec<Vec<String>> {
+    let mut deps: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges {
+        deps.entry(edge.from.as_str()).or_default().push(edge.to.as_str());
+    }
+    
+    // 0 = unvisited, 1 = on the current path, 2 = done
+    let mut state: HashMap<&str, u8> = HashMap::new();
+    let mut path: Vec<&str> = Vec::new();
+    let mut cycles = Vec::new();
+    
+    fn visit<'a>(
+        id: &'a str,
+        deps: &HashMap<&'a str, Vec<&'a str>>,
+        state: &mut HashMap<&'a str, u8>,
+        path: &mut Vec<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        match state.get(id) {
+            Some(2) => return,
+            Some(1) => {
+                let start = path.iter().position(|p| *p == id).unwrap_or(0);
+                let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+                cycle.push(id.to_string());
+                cycles.push(cycle);
+                return;
+            }
+            _ => {}
+        }
+        
+        state.insert(id, 1);
+        path.push(id);
+        for dep in deps.get(id).into_iter().flatten() {
+            visit(dep, deps, state, path, cycles);
+        }
+        path.pop();
+        state.insert(id, 2);
+    }
+    
+    for node in nodes {
+        visit(node.id.as_str(), &deps, &mut state, &mut path, &mut cycles);
+    }
+    
+    cycles
+}
+
+// Specs of the project a spec directory belongs to, archived ones included so
+// dependencies on finished work still resolve
+pub(crate) async fn project_specs(spec_dir: &Path) -> Result<Vec<SpecMetadata>, String> {
+    let project_dir = project_root(spec_dir)?;
+    scan_specs(project_dir.to_string_lossy().to_string(), Some(true)).await
+}
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+
+// A run of lines where the three versions of a file agree, or where one or
+// both sides changed what the base had
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeHunk {
+    // "unchanged", "local", "upstream", "both" (same change on each side) or "conflict"
+    kind: String,
+    base: Vec<String>,
+    local: Vec<String>,
+    upstream: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreeWayDiff {
+    hunks: Vec<MergeHunk>,
+    // Both sides' changes combined, with conflict markers where they overlap
+    merged: String,
+    conflicts: usize,
+}
+
+impl ThreeWayDiff {
+    pub(crate) fn merged(&self) -> &str {
+        &self.merged
+    }
+    
+    pub(crate) fn conflicts(&self) -> usize {
+        self.conflicts
+    }
+}
+
+// Merges the local and upstream edits of a common base, diff3 style: lines
+// the base shares with both sides anchor the merge, and what lies between
+// anchors is taken from whichever side changed it
+pub(crate) fn three_way_diff(base: &str, local: &str, upstream: &str) -> ThreeWayDiff {
+    let base_lines: Vec<&str> = base.lines().collect();
+    let local_lines: Vec<&str> = local.lines().collect();
+    let upstream_lines: Vec<&str> = upstream.lines().collect();
+    let local_matches = matching_lines(&base_lines, &local_lines);
+    let upstream_matches = matching_lines(&base_lines, &upstream_lines);
+    
+    let mut hunks: Vec<MergeHunk> = Vec::new();
+    let (mut i, mut a, mut b) = (0, 0, 0);
+    loop {
+        // Next base line kept by both sides
+        let anchor = (i..base_lines.len()).find_map(|j| match (local_matches[j], upstream_matches[j]) {
+            (Some(la), Some(ub)) => Some((j, la, ub)),
+            _ => None,
+        });
+        let (j, la, ub) = anchor.unwrap_or((base_lines.len(), local_lines.len(), upstream_lines.len()));
+        
+        let chunk = (&base_lines[i..j], &local_lines[a..la], &upstream_lines[b..ub]);
+        if !(chunk.0.is_empty() && chunk.1.is_empty() && chunk.2.is_empty()) {
+            hunks.push(changed_hunk(chunk.0, chunk.1, chunk.2));
+        }
+        
+        if anchor.is_none() {
+            break;
+        }
+        match hunks.last_mut() {
+            Some(hunk) if hunk.kind == "unchanged" => hunk.base.push(base_lines[j].to_string()),
+            _ => hunks.push(MergeHunk {
+                kind: "unchanged".to_string(),
+                base: vec![base_lines[j].to_string()],
+                local: Vec::new(),
+                upstream: Vec::new(),
+            }),
+        }
+        (i, a, b) = (j + 1, la + 1, ub + 1);
+    }
+    
+    let mut merged = Vec::new();
+    let mut conflicts = 0;
+    for hunk in &hunks {
+        match hunk.kind.as_str() {
+            "unchanged" => merged.extend(hunk.base.iter().cloned()),
+            "local" | "both" => merged.extend(hunk.local.iter().cloned()),
+            "upstream" => merged.extend(hunk.upstream.iter().cloned()),
+            _ => {
+                conflicts += 1;
+                merged.push("<<<<<<< local".to_string());
+                merged.extend(hunk.local.iter().cloned());
+                merged.push("||||||| base".to_string());
+                merged.extend(hunk.base.iter().cloned());
+                merged.push("=======".to_string());
+                merged.extend(hunk.upstream.iter().cloned());
+                merged.push(">>>>>>> bundled".to_string());
+            }
+        }
+    }
+    let mut merged = merged.join("\n");
+    if !merged.is_empty() && (upstream.ends_with('\n') || local.ends_with('\n')) {
+        merged.push('\n');
+    }
+    
+    // Unchanged hunks only carry the base; every side has the same lines
+    for hunk in &mut hunks {
+        if hunk.kind == "unchanged" {
+            hunk.local = hunk.base.clone();
+            hunk.upstream = hunk.base.clone();
+        }
+    }
+    
+    ThreeWayDiff { hunks, merged, conflicts }
+}
+
+fn changed_hunk(base: &[&str], local: &[&str], upstream: &[&str]) -> MergeHunk {
+    let kind = if local == upstream {
+        "both"
+    } else if local == base {
+        "upstream"
+    } else if upstream == base {
+        "local"
+    } else {
+        "conflict"
+    };
+    let owned = |lines: &[&str]| lines.iter().map(|line| line.to_string()).collect();
+    MergeHunk {
+        kind: kind.to_string(),
+        base: owned(base),
+        local: owned(local),
+        upstream: owned(upstream),
+    }
+}
+
+// For each line of `from`, the line of `to` it lines up with in a longest
+// common subsequence of the two
+fn matching_lines(from: &[&str], to: &[&str]) -> Vec<Option<usize>> {
+    let (n, m) = (from.len(), to.len());
+    // lengths[i][j] = LCS length of from[i..] and to[j..]
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if from[i] == to[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    
+    let mut matches = vec![None; n];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if from[i] == to[j] {
+            matches[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
@@ -0,0 +1,459 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, SpecMetadata, AGENT_SDD_COMMANDS};
+use crate::dependencies::build_dependency_graph;
+use crate::git::run_git;
+use crate::init::{bundled_files, is_executable, make_executable};
+use crate::settings::SettingsStore;
+use crate::specs::ARCHIVE_DIR;
+use crate::stale::{find_stale_tasks, stale_threshold};
+use crate::tasks::{load_tasks_file, validate_tasks};
+
+// Directories commands and the dashboard rely on, which every install profile
+// creates; `fix_directories` creates them
+pub(crate) const REQUIRED_DIRECTORIES: [&str; 4] = ["product", "specs", "instructions", "scripts"];
+// Problems listed per check before the rest are summarized as a count
+const MAX_LISTED_PROBLEMS: usize = 5;
+
+// The checks `run_doctor` runs, in order. Add a check by writing a function
+// that inspects the context and appending it here, with a fix if there's a
+// safe one.
+const CHECKS: [DoctorCheck; 10] = [
+    DoctorCheck { id: "structure", name: ".agent-sdd structure", run: check_structure, fix: None },
+    DoctorCheck { id: "directories", name: "Required directories", run: check_directories, fix: Some(fix_directories) },
+    DoctorCheck { id: "scripts", name: "Command scripts", run: check_scripts, fix: Some(fix_scripts) },
+    DoctorCheck { id: "executable", name: "Script permissions", run: check_executable, fix: Some(fix_executable) },
+    DoctorCheck { id: "bash", name: "bash", run: check_bash, fix: None },
+    DoctorCheck { id: "claude", name: "Claude Code CLI", run: check_claude, fix: None },
+    DoctorCheck { id: "git", name: "git", run: check_git, fix: None },
+    DoctorCheck { id: "tasks_json", name: "tasks.json files", run: check_tasks_json, fix: None },
+    DoctorCheck { id: "stale_tasks", name: "Stale in-progress tasks", run: check_stale_tasks, fix: None },
+    DoctorCheck { id: "dependencies", name: "Task dependencies", run: check_dependencies, fix: None },
+];
+
+// Returns None when the check doesn't apply, e.g. script checks without .agent-sdd
+type CheckFn = fn(&DoctorContext) -> Option<CheckOutcome>;
+// Only for fixes that can't lose anything: creating what's missing, chmod.
+// Returns a summary of what it did.
+type FixFn = fn(&DoctorContext) -> Result<String, String>;
+
+struct DoctorCheck {
+    id: &'static str,
+    name: &'static str,
+    run: CheckFn,
+    fix: Option<FixFn>,
+}
+
+// What checks look at, gathered once per run
+struct DoctorContext {
+    project_dir: PathBuf,
+    agent_sdd_dir: PathBuf,
+    specs: Vec<SpecMetadata>,
+    stale_after_days: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+struct CheckOutcome {
+    status: CheckStatus,
+    message: String,
+    remediation: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckResult {
+    id: String,
+    name: String,
+    status: CheckStatus,
+    message: String,
+    remediation: Option<String>,
+    // Whether `apply_doctor_fix` can fix this check's problem
+    fixable: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DoctorReport {
+    checks: Vec<CheckResult>,
+    passed: usize,
+    warnings: usize,
+    failures: usize,
+}
+
+#[tauri::command]
+pub async fn run_doctor(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    project_path: String,
+) -> Result<DoctorReport, String> {
+    let context = doctor_context(&project_path, stale_threshold(&app, &settings, None)).await?;
+    // Checks run tools and walk git history, so keep them off the async runtime
+    let checks: Vec<CheckResult> = tokio::task::spawn_blocking(move || {
+        CHECKS.iter()
+            .filter_map(|check| run_check(check, &context))
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Doctor checks failed: {}", e))?;
+    
+    let count = |status: CheckStatus| checks.iter().filter(|check| check.status == status).count();
+    Ok(DoctorReport {
+        passed: count(CheckStatus::Pass),
+        warnings: count(CheckStatus::Warn),
+        failures: count(CheckStatus::Fail),
+        checks,
+    })
+}
+
+// Applies a check's safe fix, then runs the check again
+#[tauri::command]
+pub async fn apply_doctor_fix(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    project_path: String,
+    check_id: String,
+) -> Result<CheckResult, String> {
+    let check = CHECKS.iter()
+        .find(|check| check.id == check_id)
+        .ok_or_else(|| format!("Unknown check '{}'", check_id))?;
+    let fix = check.fix.ok_or_else(|| format!("Check '{}' has no automatic fix", check.name))?;
+    
+    let stale_after_days = stale_threshold(&app, &settings, None);
+    let context = doctor_context(&project_path, stale_after_days).await?;
+    let summary = tokio::task::spawn_blocking(move || fix(&context))
+        .await
+        .map_err(|e| format!("Doctor fix failed: {}", e))??;
+    log::info!("Doctor fix for {}: {}", check.id, summary);
+    
+    let context = doctor_context(&project_path, stale_after_days).await?;
+    tokio::task::spawn_blocking(move || run_check(check, &context))
+        .await
+        .map_err(|e| format!("Doctor check failed: {}", e))?
+        .ok_or_else(|| format!("Check '{}' no longer applies", check.name))
+}
+
+async fn doctor_context(project_path: &str, stale_after_days: u32) -> Result<DoctorContext, String> {
+    let project_dir = PathBuf::from(project_path);
+    if !project_dir.is_dir() {
+        return Err("Project path does not exist or is not a directory".to_string());
+    }
+    let specs = scan_specs(project_path.to_string(), Some(true)).await?;
+    Ok(DoctorContext {
+        agent_sdd_dir: project_dir.join(".agent-sdd"),
+        project_dir,
+        specs,
+        stale_after_days,
+    })
+}
+
+fn run_check(check: &DoctorCheck, context: &DoctorContext) -> Option<CheckResult> {
+    let outcome = (check.run)(context)?;
+    Some(CheckResult {
+        id: check.id.to_string(),
+        name: check.name.to_string(),
+        fixable: check.fix.is_some() && outcome.status != CheckStatus::Pass,
+        status: outcome.status,
+        message: outcome.message,
+        remediation: outcome.remediation,
+    })
+}
+
+fn pass(message: impl Into<String>) -> Option<CheckOutcome> {
+    Some(CheckOutcome { status: CheckStatus::Pass, message: message.into(), remediation: None })
+}
+
+fn problem(status: CheckStatus, message: impl Into<String>, remediation: impl Into<String>) -> Option<CheckOutcome> {
+    Some(CheckOutcome { status, message: message.into(), remediation: Some(remediation.into()) })
+}
+
+fn check_structure(context: &DoctorContext) -> Option<CheckOutcome> {
+    if context.agent_sdd_dir.is_dir() {
+        pass(".agent-sdd directory found")
+    } else {
+        problem(
+            CheckStatus::Fail,
+            "No .agent-sdd directory in this project",
+            "Install Agent-SDD with the installer wizard",
+        )
+    }
+}
+
+fn check_directories(context: &DoctorContext) -> Option<CheckOutcome> {
+    if !context.agent_sdd_dir.is_dir() {
+        return None;
+    }
+    let missing = missing_directories(context);
+    if missing.is_empty() {
+        return pass("All required directories exist");
+    }
+    problem(
+        CheckStatus::Warn,
+        format!("Missing {}", missing.iter().map(|dir| format!(".agent-sdd/{}", dir)).collect::<Vec<_>>().join(", ")),
+        "Create the missing directories, or repair the structure to also add their standard files",
+    )
+}
+
+fn fix_directories(context: &DoctorContext) -> Result<String, String> {
+    let missing = missing_directories(context);
+    for dir in &missing {
+        fs::create_dir_all(context.agent_sdd_dir.join(dir))
+            .map_err(|e| format!("Failed to create .agent-sdd/{}: {}", dir, e))?;
+    }
+    Ok(format!("Created {} directories", missing.len()))
+}
+
+fn missing_directories(context: &DoctorContext) -> Vec<&'static str> {
+    REQUIRED_DIRECTORIES.iter()
+        .copied()
+        .filter(|dir| !context.agent_sdd_dir.join(dir).is_dir())
+        .collect()
+}
+
+fn check_scripts(context: &DoctorContext) -> Option<CheckOutcome> {
+    if !context.agent_sdd_dir.is_dir() {
+        return None;
+    }
+    let missing = missing_scripts(context);
+    if missing.is_empty() {
+        return pass(format!("Scripts found for all {} commands", AGENT_SDD_COMMANDS.len()));
+    }
+    problem(
+        CheckStatus::Fail,
+        format!("No script for {}, so running them fails", missing.join(", ")),
+        "Install the standard scripts into .agent-sdd/scripts",
+    )
+}
+
+// Writes the bundled version of each missing command script, and the runner
+// they share if it's missing too. Existing scripts are left alone.
+fn fix_scripts(context: &DoctorContext) -> Result<String, String> {
+    let scripts_dir = context.agent_sdd_dir.join("scripts");
+    fs::create_dir_all(&scripts_dir)
+        .map_err(|e| format!("Failed to create .agent-sdd/scripts: {}", e))?;
+    
+    let mut installed = Vec::new();
+    for (path, content) in bundled_files(&["scripts"]) {
+        let target = context.agent_sdd_dir.join(&path);
+        if target.exists() {
+            continue;
+        }
+        fs::write(&target, content).map_err(|e| format!("Failed to create {}: {}", path, e))?;
+        make_executable(&target)?;
+        installed.push(path);
+    }
+    Ok(format!("Installed {}", installed.join(", ")))
+}
+
+fn missing_scripts(context: &DoctorContext) -> Vec<&'static str> {
+    AGENT_SDD_COMMANDS.iter()
+        .copied()
+        .filter(|command| !context.agent_sdd_dir.join("scripts").join(format!("{}.sh", command)).is_file())
+        .collect()
+}
+
+fn check_executable(context: &DoctorContext) -> Option<CheckOutcome> {
+    let scripts = project_scripts(context);
+    if scripts.is_empty() {
+        return None;
+    }
+    let not_executable: Vec<String> = scripts.iter()
+        .filter(|script| !is_executable(script))
+        .map(|script| file_name(script))
+        .collect();
+    if not_executable.is_empty() {
+        return pass(format!("All {} scripts are executable", scripts.len()));
+    }
+    problem(
+        CheckStatus::Warn,
+        format!("Not executable: {}", list_problems(&not_executable)),
+        "Make the scripts executable (chmod +x .agent-sdd/scripts/*.sh)",
+    )
+}
+
+fn fix_executable(context: &DoctorContext) -> Result<String, String> {
+    let mut fixed = 0;
+    for script in project_scripts(context) {
+        if !is_executable(&script) {
+            make_executable(&script)?;
+            fixed += 1;
+        }
+    }
+    Ok(format!("Made {} scripts executable", fixed))
+}
+
+fn project_scripts(context: &DoctorContext) -> Vec<PathBuf> {
+    let mut scripts: Vec<PathBuf> = fs::read_dir(context.agent_sdd_dir.join("scripts"))
+        .map(|entries| entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "sh"))
+            .collect())
+        .unwrap_or_default();
+    scripts.sort();
+    scripts
+}
+
+fn check_bash(_context: &DoctorContext) -> Option<CheckOutcome> {
+    match find_executable("bash") {
+        Some(path) => pass(format!("Found at {}", path.display())),
+        None => problem(
+            CheckStatus::Fail,
+            "bash is not on PATH, and every command script runs through it",
+            "Install bash (on Windows, Git Bash or WSL) and make sure it's on the PATH the dashboard starts with",
+        ),
+    }
+}
+
+fn check_claude(_context: &DoctorContext) -> Option<CheckOutcome> {
+    match find_executable("claude") {
+        Some(path) => pass(format!("Found at {}", path.display())),
+        None => problem(
+            CheckStatus::Fail,
+            "claude is not on PATH, so the standard scripts can't run instructions",
+            "Install Claude Code (npm install -g @anthropic-ai/claude-code) and make sure it's on the PATH the dashboard starts with",
+        ),
+    }
+}
+
+fn check_git(context: &DoctorContext) -> Option<CheckOutcome> {
+    if find_executable("git").is_none() {
+        return problem(
+            CheckStatus::Warn,
+            "git is not on PATH; history, moves and activity tracking are unavailable",
+            "Install git",
+        );
+    }
+    match run_git(&context.project_dir, &["rev-parse", "--is-inside-work-tree"]) {
+        Some(_) => pass("Project is a git repository"),
+        None => problem(
+            CheckStatus::Warn,
+            "Project is not a git repository; tasks can't be committed or traced",
+            "Run git init in the project directory",
+        ),
+    }
+}
+
+fn check_tasks_json(context: &DoctorContext) -> Option<CheckOutcome> {
+    let spec_dirs = spec_dirs(context);
+    if spec_dirs.is_empty() {
+        return None;
+    }
+    
+    let mut broken = Vec::new();
+    let mut invalid = Vec::new();
+    for spec_dir in &spec_dirs {
+        match load_tasks_file(spec_dir) {
+            Ok(tasks_file) => {
+                let errors = validate_tasks(&tasks_file.tasks);
+                if !errors.is_empty() {
+                    invalid.push(format!("{}: {}", file_name(spec_dir), errors.join("; ")));
+                }
+            }
+            Err(e) => broken.push(format!("{}: {}", file_name(spec_dir), e)),
+        }
+    }
+    
+    if !broken.is_empty() {
+        return problem(
+            CheckStatus::Fail,
+            format!("{} of {} specs can't be read: {}", broken.len(), spec_dirs.len(), list_problems(&broken)),
+            "Fix the JSON syntax in the listed tasks.json files; their specs are hidden until then",
+        );
+    }
+    if !invalid.is_empty() {
+        return problem(
+            CheckStatus::Warn,
+            format!("{} specs have invalid tasks: {}", invalid.len(), list_problems(&invalid)),
+            "Edit the listed tasks so ids are unique and dependencies point at existing tasks",
+        );
+    }
+    pass(format!("All {} tasks.json files are valid", spec_dirs.len()))
+}
+
+// Spec directories with a tasks.json, archived ones included
+fn spec_dirs(context: &DoctorContext) -> Vec<PathBuf> {
+    let specs_dir = context.agent_sdd_dir.join("specs");
+    let mut dirs = Vec::new();
+    for dir in [specs_dir.clone(), specs_dir.join(ARCHIVE_DIR)] {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            dirs.extend(entries.flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.join("tasks.json").is_file()));
+        }
+    }
+    dirs.sort();
+    dirs
+}
+
+fn check_stale_tasks(context: &DoctorContext) -> Option<CheckOutcome> {
+    if !context.specs.iter().flat_map(|spec| &spec.tasks).any(|task| task.status == "in_progress") {
+        return None;
+    }
+    
+    let stale: Vec<String> = find_stale_tasks(&context.project_dir, &context.specs, context.stale_after_days)
+        .iter()
+        .map(|task| format!("{} {} ({})", task.spec_id, task.task_id, task.reasons.join(", ")))
+        .collect();
+    
+    if stale.is_empty() {
+        return pass("In-progress tasks have recent activity");
+    }
+    problem(
+        CheckStatus::Warn,
+        format!("{} stale in-progress tasks: {}", stale.len(), list_problems(&stale)),
+        "Finish these tasks, or reset them to pending if nobody is working on them",
+    )
+}
+
+fn check_dependencies(context: &DoctorContext) -> Option<CheckOutcome> {
+    if context.specs.is_empty() {
+        return None;
+    }
+    let graph = build_dependency_graph(&context.specs);
+    let (unresolved, cycles) = graph.problems();
+    
+    if !cycles.is_empty() {
+        let cycles: Vec<String> = cycles.iter().map(|cycle| cycle.join(" → ")).collect();
+        return problem(
+            CheckStatus::Fail,
+            format!("Circular dependencies: {}", list_problems(&cycles)),
+            "Remove one dependency from each cycle; tasks in a cycle can never become ready",
+        );
+    }
+    if !unresolved.is_empty() {
+        let unresolved: Vec<String> = unresolved.iter().map(|dep| dep.describe()).collect();
+        return problem(
+            CheckStatus::Warn,
+            format!("Broken dependencies: {}", list_problems(&unresolved)),
+            "Point the dependencies at existing tasks, using spec-id/TASK-ID for other specs",
+        );
+    }
+    pass("All dependencies resolve")
+}
+
+// Path of an executable on PATH, trying Windows extensions where they apply
+fn find_executable(name: &str) -> Option<PathBuf> {
+    let extensions: &[&str] = if cfg!(windows) { &["exe", "cmd", "bat"] } else { &[""] };
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .flat_map(|dir| extensions.iter().map(move |ext| dir.join(name).with_extension(ext)))
+        .find(|candidate| candidate.is_file())
+}
+
+fn list_problems(problems: &[String]) -> String {
+    let mut listed = problems.iter().take(MAX_LISTED_PROBLEMS).cloned().collect::<Vec<_>>().join("; ");
+    if problems.len() > MAX_LISTED_PROBLEMS {
+        listed.push_str(&format!(" and {} more", problems.len() - MAX_LISTED_PROBLEMS));
+    }
+    listed
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+}
@@ -0,0 +1,338 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::commands::write_file_atomic;
+use crate::diff::{three_way_diff, ThreeWayDiff};
+use crate::init::{bundled_files, is_script, make_executable, FRAMEWORK_VERSION};
+
+// Bundled directories a project gets upgrades for. Standards and product docs
+// are the project's own and never touched.
+pub(crate) const FRAMEWORK_SECTIONS: [&str; 3] = ["instructions", "agents", "scripts"];
+
+// `.agent-sdd/.framework/` records the framework version a project has and a
+// copy of each framework file as installed, the base for three-way diffs
+const FRAMEWORK_DIR: &str = ".framework";
+const MANIFEST_FILE: &str = "manifest.json";
+const BASE_DIR: &str = "base";
+const BACKUPS_DIR: &str = ".backups";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FrameworkManifest {
+    version: String,
+    updated: String,
+    // Path relative to `.agent-sdd/` -> sha256 of the content installed
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrameworkStatus {
+    // None when the project predates the manifest, e.g. set up by setup-agent-sdd.sh
+    installed_version: Option<String>,
+    bundled_version: String,
+    up_to_date: bool,
+    files: Vec<FrameworkFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrameworkFile {
+    path: String,
+    // "current", "missing", "outdated" (unchanged locally, newer bundled),
+    // "modified" (changed locally only), "conflict" (changed on both sides)
+    // or "unknown" (differs from bundled with no record of what was installed)
+    status: String,
+    local_hash: Option<String>,
+    base_hash: Option<String>,
+    bundled_hash: String,
+    // Set for modified, conflicting and unknown files. Unknown files are diffed
+    // against the bundled version as the base.
+    diff: Option<ThreeWayDiff>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpgradeReport {
+    from_version: Option<String>,
+    to_version: String,
+    // Where the replaced files were copied to, relative to the project root
+    backup_dir: Option<String>,
+    added: Vec<String>,
+    updated: Vec<String>,
+    merged: Vec<String>,
+    // Files left as they are, with why
+    kept: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn check_agent_sdd_version(project_path: String) -> Result<FrameworkStatus, String> {
+    framework_status(&agent_sdd_dir(Path::new(&project_path))?)
+}
+
+// Brings framework files up to the bundled version, backing up every file it
+// replaces first. Missing and outdated files are always updated; modified,
+// conflicting and unknown ones only when `resolutions` names them with
+// "bundled" (take the new version), "merge" (apply the three-way merge, which
+// must be free of conflicts) or "local" (keep, the default).
+#[tauri::command]
+pub async fn upgrade_agent_sdd(
+    project_path: String,
+    resolutions: Option<HashMap<String, String>>,
+) -> Result<UpgradeReport, String> {
+    let project_dir = Path::new(&project_path);
+    let agent_sdd_dir = agent_sdd_dir(project_dir)?;
+    let resolutions = resolutions.unwrap_or_default();
+    let status = framework_status(&agent_sdd_dir)?;
+    let bundled: HashMap<String, String> = bundled_files(&FRAMEWORK_SECTIONS).into_iter().collect();
+    
+    for (path, resolution) in &resolutions {
+        if !bundled.contains_key(path) {
+            return Err(format!("'{}' is not a framework file", path));
+        }
+        if !["bundled", "merge", "local"].contains(&resolution.as_str()) {
+            return Err(format!("Unknown resolution '{}' for {} (expected bundled, merge or local)", resolution, path));
+        }
+    }
+    
+    // Work out every write before touching anything
+    let mut writes: Vec<(String, String)> = Vec::new();
+    // Files already matching the bundled version only need their base copy
+    let mut current: Vec<&str> = Vec::new();
+    let mut report = UpgradeReport {
+        from_version: status.installed_version.clone(),
+        to_version: FRAMEWORK_VERSION.to_string(),
+        ..UpgradeReport::default()
+    };
+    let mut manifest = read_manifest(&agent_sdd_dir).unwrap_or_default();
+    // Whether a file kept as it is still lacks upstream changes
+    let mut behind = false;
+    for file in &status.files {
+        let content = &bundled[&file.path];
+        let resolution = resolutions.get(&file.path).map(String::as_str).unwrap_or("local");
+        match (file.status.as_str(), resolution) {
+            ("current", _) => current.push(&file.path),
+            ("missing", _) => {
+                writes.push((file.path.clone(), content.clone()));
+                report.added.push(file.path.clone());
+            }
+            ("outdated", _) | (_, "bundled") => {
+                writes.push((file.path.clone(), content.clone()));
+                report.updated.push(file.path.clone());
+            }
+            (_, "merge") => {
+                let diff = file.diff.as_ref().ok_or_else(|| format!("No merge available for {}", file.path))?;
+                if diff.conflicts() > 0 {
+                    return Err(format!(
+                        "{} has {} conflicting change(s); resolve it with bundled or local instead",
+                        file.path, diff.conflicts()
+                    ));
+                }
+                writes.push((file.path.clone(), diff.merged().to_string()));
+                report.merged.push(file.path.clone());
+            }
+            (status, _) => {
+                behind |= status != "modified";
+                report.kept.push(format!("{} ({})", file.path, status));
+            }
+        }
+    }
+    
+    if !writes.is_empty() {
+        report.backup_dir = Some(backup_files(project_dir, &agent_sdd_dir, &writes)?);
+    }
+    
+    for (path, content) in &writes {
+        let target = agent_sdd_dir.join(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        write_file_atomic(&target, content)?;
+        if is_script(path) {
+            make_executable(&target)?;
+        }
+        
+        // Merged files are based on the bundled version from now on
+        let bundled_content = &bundled[path];
+        write_base(&agent_sdd_dir, path, bundled_content)?;
+        manifest.files.insert(path.clone(), content_hash(bundled_content));
+    }
+    for path in current {
+        write_base(&agent_sdd_dir, path, &bundled[path])?;
+        manifest.files.insert(path.to_string(), content_hash(&bundled[path]));
+    }
+    for path in report.added.iter().chain(&report.updated).chain(&report.merged) {
+        log::info!("Upgraded framework file {}", path);
+    }
+    
+    // The project stays on its old version until every upstream change is in
+    if !behind {
+        manifest.version = FRAMEWORK_VERSION.to_string();
+    }
+    manifest.updated = chrono::Local::now().to_rfc3339();
+    write_manifest(&agent_sdd_dir, &manifest)?;
+    
+    Ok(report)
+}
+
+fn framework_status(agent_sdd_dir: &Path) -> Result<FrameworkStatus, String> {
+    let manifest = read_manifest(agent_sdd_dir);
+    let mut files = Vec::new();
+    
+    for (path, bundled) in bundled_files(&FRAMEWORK_SECTIONS) {
+        let bundled_hash = content_hash(&bundled);
+        let local = fs::read_to_string(agent_sdd_dir.join(&path)).ok();
+        let local_hash = local.as_deref().map(content_hash);
+        let base = fs::read_to_string(base_path(agent_sdd_dir, &path)).ok();
+        let base_hash = manifest.as_ref().and_then(|manifest| manifest.files.get(&path).cloned());
+        // A base copy that doesn't match the manifest can't be trusted
+        let base = base.filter(|base| Some(content_hash(base)) == base_hash);
+        
+        let (status, diff) = match (&local, &base) {
+            (None, _) => ("missing", None),
+            (Some(_), _) if local_hash.as_deref() == Some(bundled_hash.as_str()) => ("current", None),
+            (Some(_), Some(_)) if local_hash == base_hash => ("outdated", None),
+            (Some(local), Some(base)) => {
+                let status = if *base == bundled { "modified" } else { "conflict" };
+                (status, Some(three_way_diff(base, local, &bundled)))
+            }
+            (Some(local), None) => ("unknown", Some(three_way_diff(&bundled, local, &bundled))),
+        };
+        
+        files.push(FrameworkFile {
+            path,
+            status: status.to_string(),
+            local_hash,
+            base_hash,
+            bundled_hash,
+            diff,
+        });
+    }
+    
+    let installed_version = manifest.map(|manifest| manifest.version).filter(|version| !version.is_empty());
+    let up_to_date = installed_version.as_deref() == Some(FRAMEWORK_VERSION)
+        && files.iter().all(|file| file.status == "current" || file.status == "modified");
+    Ok(FrameworkStatus {
+        installed_version,
+        bundled_version: FRAMEWORK_VERSION.to_string(),
+        up_to_date,
+        files,
+    })
+}
+
+// Records the framework files an install or repair wrote so later upgrades can
+// tell local edits from outdated files. A project without a manifest is only
+// on the bundled version if every framework file was just written.
+pub(crate) fn record_installed_files(agent_sdd_dir: &Path, files: &[(String, String)]) -> Result<(), String> {
+    let framework_files: Vec<&(String, String)> = files.iter()
+        .filter(|(path, _)| FRAMEWORK_SECTIONS.iter().any(|section| path.starts_with(&format!("{}/", section))))
+        .collect();
+    if framework_files.is_empty() {
+        return Ok(());
+    }
+    
+    let mut manifest = read_manifest(agent_sdd_dir).unwrap_or_else(|| {
+        let complete = bundled_files(&FRAMEWORK_SECTIONS).iter()
+            .all(|(path, _)| framework_files.iter().any(|(written, _)| written == path));
+        FrameworkManifest {
+            version: if complete { FRAMEWORK_VERSION.to_string() } else { String::new() },
+            ..FrameworkManifest::default()
+        }
+    });
+    for (path, content) in framework_files {
+        write_base(agent_sdd_dir, path, content)?;
+        manifest.files.insert(path.clone(), content_hash(content));
+    }
+    manifest.updated = chrono::Local::now().to_rfc3339();
+    write_manifest(agent_sdd_dir, &manifest)
+}
+
+// Copies the current version of each file about to be written into
+// `.agent-sdd/.backups/<timestamp>/`. Returns that directory relative to the project.
+fn backup_files(project_dir: &Path, agent_sdd_dir: &Path, writes: &[(String, String)]) -> Result<String, String> {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let backups_dir = agent_sdd_dir.join(BACKUPS_DIR);
+    fs::create_dir_all(&backups_dir).map_err(|e| format!("Failed to create backup directory: {}", e))?;
+    
+    // Upgrades within the same second get a numbered directory each, rather
+    // than overwriting the earlier backup
+    let mut attempt = 1;
+    let backup_dir = loop {
+        let name = match attempt {
+            1 => format!("upgrade-{}", stamp),
+            _ => format!("upgrade-{}-{}", stamp, attempt),
+        };
+        let backup_dir = backups_dir.join(name);
+        match fs::create_dir(&backup_dir) {
+            Ok(()) => break backup_dir,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => attempt += 1,
+            Err(e) => return Err(format!("Failed to create backup directory: {}", e)),
+        }
+    };
+    
+    let manifest_path = agent_sdd_dir.join(FRAMEWORK_DIR).join(MANIFEST_FILE);
+    
+    let existing = writes.iter()
+        .map(|(path, _)| (PathBuf::from(path), agent_sdd_dir.join(path)))
+        .chain(std::iter::once((PathBuf::from(FRAMEWORK_DIR).join(MANIFEST_FILE), manifest_path)))
+        .filter(|(_, source)| source.is_file());
+    for (rel_path, source) in existing {
+        let target = backup_dir.join(rel_path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create backup directory: {}", e))?;
+        }
+        fs::copy(&source, &target)
+            .map_err(|e| format!("Failed to back up {}: {}", source.display(), e))?;
+    }
+    
+    Ok(backup_dir.strip_prefix(project_dir)
+        .unwrap_or(&backup_dir)
+        .to_string_lossy()
+        .replace('\\', "/"))
+}
+
+fn agent_sdd_dir(project_dir: &Path) -> Result<PathBuf, String> {
+    let agent_sdd_dir = project_dir.join(".agent-sdd");
+    if !agent_sdd_dir.is_dir() {
+        return Err("Project does not contain .agent-sdd directory".to_string());
+    }
+    Ok(agent_sdd_dir)
+}
+
+fn read_manifest(agent_sdd_dir: &Path) -> Option<FrameworkManifest> {
+    let path = agent_sdd_dir.join(FRAMEWORK_DIR).join(MANIFEST_FILE);
+    let content = fs::read_to_string(&path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            log::warn!("Ignoring unreadable {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+fn write_manifest(agent_sdd_dir: &Path, manifest: &FrameworkManifest) -> Result<(), String> {
+    let dir = agent_sdd_dir.join(FRAMEWORK_DIR);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let content = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize framework manifest: {}", e))?;
+    write_file_atomic(&dir.join(MANIFEST_FILE), &content)
+}
+
+fn base_path(agent_sdd_dir: &Path, path: &str) -> PathBuf {
+    agent_sdd_dir.join(FRAMEWORK_DIR).join(BASE_DIR).join(path)
+}
+
+fn write_base(agent_sdd_dir: &Path, path: &str, content: &str) -> Result<(), String> {
+    let target = base_path(agent_sdd_dir, path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    write_file_atomic(&target, content)
+}
+
+pub(crate) fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
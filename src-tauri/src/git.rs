@@ -0,0 +1,125 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use chrono::NaiveDate;
+
+const MAX_FILE_REVISIONS: usize = 200;
+
+// Runs git in the given directory, returning stdout on success. Projects that
+// aren't repositories, or machines without git, simply yield None.
+pub(crate) fn run_git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    
+    if !output.status.success() {
+        return None;
+    }
+    
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+// Returns the committed contents of a file, oldest revision first. All
+// revisions are read through a single `git cat-file --batch` process.
+pub(crate) fn file_revisions(project_dir: &Path, file: &Path) -> Vec<(NaiveDate, String)> {
+    let rel_path = match file.strip_prefix(project_dir) {
+        Ok(rel_path) => rel_path.to_string_lossy().replace('\\', "/"),
+        Err(_) => return Vec::new(),
+    };
+    
+    let max_count = format!("--max-count={}", MAX_FILE_REVISIONS);
+    let log = match run_git(project_dir, &["log", &max_count, "--format=%H %cs", "--", &rel_path]) {
+        Some(log) => log,
+        None => return Vec::new(),
+    };
+    
+    let commits: Vec<(&str, NaiveDate)> = log.lines()
+        .filter_map(|line| {
+            let (hash, date) = line.split_once(' ')?;
+            Some((hash, NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()?))
+        })
+        .collect();
+    if commits.is_empty() {
+        return Vec::new();
+    }
+    
+    // `./` makes the path relative to the project rather than the repository
+    // root, which matters when the project is a subdirectory of a monorepo
+    let requests: String = commits.iter()
+        .map(|(hash, _)| format!("{}:./{}\n", hash, rel_path))
+        .collect();
+    let output = match run_git_with_input(project_dir, &["cat-file", "--batch"], requests) {
+        Some(output) => output,
+        None => return Vec::new(),
+    };
+    
+    let mut revisions: Vec<(NaiveDate, String)> = commits.iter()
+        .zip(batch_contents(&output, commits.len()))
+        .filter_map(|((_, date), content)| Some((*date, content?)))
+        .collect();
+    
+    revisions.reverse();
+    revisions
+}
+
+// Date of the latest commit touching a file or directory
+pub(crate) fn last_commit_date(project_dir: &Path, path: &Path) -> Option<NaiveDate> {
+    let rel_path = path.strip_prefix(project_dir).ok()?.to_string_lossy().replace('\\', "/");
+    let date = run_git(project_dir, &["log", "-1", "--format=%cs", "--", &rel_path])?;
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()
+}
+
+// Like `run_git`, feeding `input` to git's stdin from a separate thread so a
+// full stdout pipe can't stall the write
+fn run_git_with_input(dir: &Path, args: &[&str], input: String) -> Option<Vec<u8>> {
+    let mut child = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    
+    let mut stdin = child.stdin.take()?;
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().ok()?;
+    writer.join().ok()?.ok()?;
+    
+    if !output.status.success() {
+        return None;
+    }
+    Some(output.stdout)
+}
+
+// Splits `git cat-file --batch` output into one entry per requested object,
+// None for objects git reported missing
+fn batch_contents(output: &[u8], count: usize) -> Vec<Option<String>> {
+    let mut contents = Vec::with_capacity(count);
+    let mut rest = output;
+    
+    while contents.len() < count {
+        let header_end = match rest.iter().position(|byte| *byte == b'\n') {
+            Some(header_end) => header_end,
+            None => break,
+        };
+        let header = String::from_utf8_lossy(&rest[..header_end]).to_string();
+        rest = &rest[header_end + 1..];
+        
+        // "<object> missing", or "<oid> <type> <size>" followed by the content and a newline
+        let size = match header.rsplit(' ').next().and_then(|size| size.parse::<usize>().ok()) {
+            Some(size) if !header.ends_with(" missing") && size <= rest.len() => size,
+            _ => {
+                contents.push(None);
+                continue;
+            }
+        };
+        contents.push(Some(String::from_utf8_lossy(&rest[..size]).to_string()));
+        rest = rest.get(size + 1..).unwrap_or_default();
+    }
+    
+    contents
+}
@@ -0,0 +1,105 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{CommandRequest, CommandResult};
+
+const HISTORY_DIR: &str = ".history";
+const EXECUTIONS_FILE: &str = "executions.jsonl";
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+// One line of `.agent-sdd/.history/executions.jsonl`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub(crate) timestamp: String,
+    pub(crate) command: String,
+    pub(crate) task_id: String,
+    pub(crate) spec_id: String,
+    pub(crate) success: bool,
+    pub(crate) exit_code: Option<i32>,
+    pub(crate) duration_ms: u64,
+}
+
+#[tauri::command]
+pub async fn get_execution_history(
+    project_path: String,
+    spec_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<ExecutionRecord>, String> {
+    let agent_sdd_dir = Path::new(&project_path).join(".agent-sdd");
+    if !agent_sdd_dir.exists() || !agent_sdd_dir.is_dir() {
+        return Err("Project does not contain .agent-sdd directory".to_string());
+    }
+    
+    let mut records: Vec<ExecutionRecord> = read_executions(&agent_sdd_dir)
+        .into_iter()
+        .filter(|record| spec_id.as_ref().map_or(true, |id| &record.spec_id == id))
+        .collect();
+    
+    // Newest first
+    records.reverse();
+    records.truncate(limit.unwrap_or(DEFAULT_HISTORY_LIMIT));
+    
+    Ok(records)
+}
+
+pub(crate) fn record_execution(agent_sdd_dir: &Path, request: &CommandRequest, result: &CommandResult) {
+    let record = ExecutionRecord {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        command: request.command.clone(),
+        task_id: request.task_id.clone(),
+        spec_id: Path::new(&request.spec_path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("")
+            .to_string(),
+        success: result.success,
+        exit_code: result.exit_code,
+        duration_ms: result.duration_ms,
+    };
+    
+    if let Err(e) = append_record(&executions_path(agent_sdd_dir), &record) {
+        log::warn!("Failed to record execution history: {}", e);
+    }
+}
+
+pub(crate) fn read_executions(agent_sdd_dir: &Path) -> Vec<ExecutionRecord> {
+    let content = match fs::read_to_string(executions_path(agent_sdd_dir)) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
+    };
+    
+    content.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                log::warn!("Skipping malformed execution history entry: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn executions_path(agent_sdd_dir: &Path) -> PathBuf {
+    agent_sdd_dir.join(HISTORY_DIR).join(EXECUTIONS_FILE)
+}
+
+fn append_record(path: &Path, record: &ExecutionRecord) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create history directory: {}", e))?;
+    }
+    
+    let line = serde_json::to_string(record)
+        .map_err(|e| format!("Failed to serialize execution record: {}", e))?;
+    
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open history file: {}", e))?;
+    writeln!(file, "{}", line)
+        .map_err(|e| format!("Failed to write history file: {}", e))
+}
@@ -0,0 +1,806 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::framework::record_installed_files;
+
+// Profiles `create_agent_sdd_structure` can install. The custom profile copies
+// a directory laid out like `.agent-sdd/` instead of the bundled files.
+pub(crate) const MINIMAL_PROFILE: &str = "minimal";
+pub(crate) const FULL_PROFILE: &str = "full";
+pub(crate) const CUSTOM_PROFILE: &str = "custom";
+
+// Bundled directories each built-in profile installs, on top of product/ and specs/
+const MINIMAL_SECTIONS: [&str; 2] = ["instructions", "scripts"];
+const FULL_SECTIONS: [&str; 4] = ["standards", "instructions", "agents", "scripts"];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    name: String,
+    description: String,
+    // "minimal", "full" or "custom"; defaults to full
+    #[serde(default)]
+    profile: Option<String>,
+    // Directory the custom profile copies from
+    #[serde(rename = "customDir", default)]
+    custom_dir: Option<String>,
+}
+
+// What an install or repair wrote, relative to the project root
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InitReport {
+    profile: String,
+    directories: Vec<String>,
+    files: Vec<String>,
+    // Scripts marked executable, also listed in `files` unless they already existed
+    executables: Vec<String>,
+    // Profile files that already existed and were left alone
+    skipped: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn create_agent_sdd_structure(project_path: String, config: ProjectConfig) -> Result<InitReport, String> {
+    let project_dir = Path::new(&project_path);
+    
+    if !project_dir.exists() || !project_dir.is_dir() {
+        return Err("Project path does not exist or is not a directory".to_string());
+    }
+    
+    let agent_sdd_dir = project_dir.join(".agent-sdd");
+    
+    // An empty .agent-sdd is as good as none; anything more is for repair
+    let is_empty = fs::read_dir(&agent_sdd_dir).is_ok_and(|mut entries| entries.next().is_none());
+    if agent_sdd_dir.exists() && !is_empty {
+        return Err("Agent-SDD structure already exists in this directory; repair it to add what's missing".to_string());
+    }
+    
+    let profile = profile_name(config.profile.as_deref());
+    let files = profile_files(&profile, config.custom_dir.as_deref())?;
+    let mut report = InitReport { profile, ..InitReport::default() };
+    if let Err(e) = install(&agent_sdd_dir, &config.name, &config.description, &files, &mut report) {
+        // Nothing was there before, so don't leave a half-installed structure behind
+        if let Err(cleanup) = fs::remove_dir_all(&agent_sdd_dir) {
+            log::warn!("Failed to remove partial .agent-sdd directory: {}", cleanup);
+        }
+        return Err(e);
+    }
+    
+    Ok(report)
+}
+
+// Creates whatever the profile has that the project's `.agent-sdd/` is missing:
+// directories, files and scripts, which are also made executable if they
+// aren't. Existing files are never overwritten.
+#[tauri::command]
+pub async fn repair_agent_sdd_structure(
+    project_path: String,
+    profile: Option<String>,
+    custom_dir: Option<String>,
+) -> Result<InitReport, String> {
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project path does not exist or is not a directory".to_string());
+    }
+    let agent_sdd_dir = project_dir.join(".agent-sdd");
+    if agent_sdd_dir.exists() && !agent_sdd_dir.is_dir() {
+        return Err(format!("{} exists but is not a directory", agent_sdd_dir.display()));
+    }
+    
+    let profile = profile_name(profile.as_deref());
+    let files = profile_files(&profile, custom_dir.as_deref())?;
+    let name = project_dir.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Project".to_string());
+    
+    let mut report = InitReport { profile, ..InitReport::default() };
+    install(&agent_sdd_dir, &name, "", &files, &mut report)?;
+    
+    for script in files.iter().map(|(path, _)| path).filter(|path| is_script(path)) {
+        let target = agent_sdd_dir.join(script);
+        let rel_path = format!(".agent-sdd/{}", script);
+        if !report.executables.contains(&rel_path) && !is_executable(&target) {
+            make_executable(&target)?;
+            report.executables.push(rel_path);
+        }
+    }
+    
+    Ok(report)
+}
+
+fn profile_name(profile: Option<&str>) -> String {
+    profile.map(str::trim)
+        .filter(|profile| !profile.is_empty())
+        .unwrap_or(FULL_PROFILE)
+        .to_string()
+}
+
+fn profile_files(profile: &str, custom_dir: Option<&str>) -> Result<Vec<(String, String)>, String> {
+    match profile {
+        MINIMAL_PROFILE => Ok(bundled_files(&MINIMAL_SECTIONS)),
+        FULL_PROFILE => Ok(bundled_files(&FULL_SECTIONS)),
+        CUSTOM_PROFILE => {
+            let custom_dir = custom_dir
+                .filter(|dir| !dir.trim().is_empty())
+                .ok_or("The custom profile needs a directory to copy from")?;
+            custom_files(Path::new(custom_dir))
+        }
+        other => Err(format!(
+            "Unknown profile '{}' (expected {}, {} or {})",
+            other, MINIMAL_PROFILE, FULL_PROFILE, CUSTOM_PROFILE
+        )),
+    }
+}
+
+// Writes the profile's files that don't exist yet, then records the bundled
+// framework files among them for upgrades
+fn install(
+    agent_sdd_dir: &Path,
+    name: &str,
+    description: &str,
+    files: &[(String, String)],
+    report: &mut InitReport,
+) -> Result<(), String> {
+    if !agent_sdd_dir.is_dir() {
+        fs::create_dir(agent_sdd_dir)
+            .map_err(|e| format!("Failed to create .agent-sdd directory: {}", e))?;
+        report.directories.push(".agent-sdd".to_string());
+    }
+    
+    // Product files describe this project, so they come from the config unless
+    // a custom profile brings its own
+    let product_files: Vec<(String, String)> = product_files(name, description).into_iter()
+        .filter(|(path, _)| !files.iter().any(|(existing, _)| existing == path))
+        .collect();
+    
+    for dir_name in ["product", "specs"] {
+        create_dir(agent_sdd_dir, dir_name, report)?;
+    }
+    let mut written = Vec::new();
+    for (path, content) in files.iter().chain(&product_files) {
+        if let Some(parent) = Path::new(path).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            create_dir(agent_sdd_dir, &parent.to_string_lossy(), report)?;
+        }
+        
+        let target = agent_sdd_dir.join(path);
+        if target.exists() {
+            report.skipped.push(format!(".agent-sdd/{}", path));
+            continue;
+        }
+        fs::write(&target, content).map_err(|e| format!("Failed to create {}: {}", path, e))?;
+        report.files.push(format!(".agent-sdd/{}", path));
+        written.push((path.clone(), content.clone()));
+        
+        if is_script(path) {
+            make_executable(&target)?;
+            report.executables.push(format!(".agent-sdd/{}", path));
+        }
+    }
+    
+    // Custom profiles bring their own versions of framework files, which
+    // upgrades shouldn't treat as the bundled ones
+    if report.profile != CUSTOM_PROFILE {
+        record_installed_files(agent_sdd_dir, &written)?;
+    }
+    
+    Ok(())
+}
+
+fn create_dir(agent_sdd_dir: &Path, rel_path: &str, report: &mut InitReport) -> Result<(), String> {
+    let mut current = PathBuf::new();
+    for part in Path::new(rel_path).components() {
+        current.push(part);
+        let dir = agent_sdd_dir.join(&current);
+        if dir.is_dir() {
+            continue;
+        }
+        fs::create_dir(&dir)
+            .map_err(|e| format!("Failed to create {} directory: {}", current.display(), e))?;
+        report.directories.push(format!(".agent-sdd/{}", current.to_string_lossy().replace('\\', "/")));
+    }
+    Ok(())
+}
+
+// Bundled files under the given top-level directories, as (path, content)
+pub(crate) fn bundled_files(sections: &[&str]) -> Vec<(String, String)> {
+    AGENT_SDD_FILES.iter()
+        .filter(|(path, _)| sections.iter().any(|section| path.starts_with(&format!("{}/", section))))
+        .map(|(path, content)| (path.to_string(), content.to_string()))
+        .collect()
+}
+
+// Every file in a custom profile directory, which is either laid out like
+// `.agent-sdd/` or contains one
+fn custom_files(dir: &Path) -> Result<Vec<(String, String)>, String> {
+    if !dir.is_dir() {
+        return Err(format!("Profile directory not found: {}", dir.display()));
+    }
+    let root = if dir.join(".agent-sdd").is_dir() { dir.join(".agent-sdd") } else { dir.to_path_buf() };
+    
+    let mut files = Vec::new();
+    collect_files(&root, &root, &mut files)?;
+    if files.is_empty() {
+        return Err(format!("Profile directory is empty: {}", dir.display()));
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<(String, String)>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
+        // Skips .git, .DS_Store and the like
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            let rel_path = path.strip_prefix(root)
+                .map_err(|_| format!("{} is outside the profile directory", path.display()))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.push((rel_path, content));
+        }
+    }
+    Ok(())
+}
+
+fn product_files(name: &str, description: &str) -> Vec<(String, String)> {
+    let description = if description.trim().is_empty() {
+        "Project description coming soon."
+    } else {
+        description.trim()
+    };
+    let today = chrono::Local::now().format("%Y-%m-%d");
+    
+    vec![
+        (
+            "product/overview.md".to_string(),
+            format!(
+                "# {}\n\n{}\n\n## Mission\n\n- \n\n## Target Users\n\n- \n\n## Key Features\n\n- \n",
+                name, description
+            ),
+        ),
+        (
+            "product/roadmap.md".to_string(),
+            format!(
+                "# {} Roadmap\n\n## Phase 0: Already Completed\n- [x] Agent-SDD setup\n\n## Phase 1: Current Development\n- [ ] \n\n## Progress Log\n\n**[{}] – Project Initialized**\n- **What:** Created Agent-SDD structure\n- **Why:** Establish organized project foundation\n- **Impact:** Enables systematic development and documentation\n",
+                name, today
+            ),
+        ),
+        (
+            "product/decisions.md".to_string(),
+            format!(
+                "# {} Decisions\n\nRecord decisions as `## YYYY-MM-DD: Title` followed by `- **Decision:**` and `- **Rationale:**` bullets.\n",
+                name
+            ),
+        ),
+    ]
+}
+
+pub(crate) fn is_script(path: &str) -> bool {
+    path.starts_with("scripts/") && path.ends_with(".sh")
+}
+
+pub(crate) fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    path.is_file()
+}
+
+pub(crate) fn make_executable(path: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(path)
+            .map_err(|e| format!("Failed to read permissions of {}: {}", path.display(), e))?
+            .permissions();
+        permissions.set_mode(0o755); // rwxr-xr-x
+        fs::set_permissions(path, permissions)
+            .map_err(|e| format!("Failed to make {} executable: {}", path.display(), e))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+// Version of the bundled files below; bump it whenever they change so
+// `check_agent_sdd_version` offers the new content to existing projects
+pub(crate) const FRAMEWORK_VERSION: &str = "1.0.0";
+
+// The standard Agent-SDD files, relative to `.agent-sdd/`
+pub(crate) const AGENT_SDD_FILES: [(&str, &str); 28] = [
+    ("standards/tech-stack.md", TECH_STACK),
+    ("standards/code-style.md", CODE_STYLE),
+    ("standards/best-practices.md", BEST_PRACTICES),
+    ("standards/theme-standards.md", THEME_STANDARDS),
+    ("instructions/sdd-plan-product.md", PLAN_PRODUCT),
+    ("instructions/sdd-create-spec.md", CREATE_SPEC),
+    ("instructions/sdd-execute-task.md", EXECUTE_TASK),
+    ("instructions/sdd-fix.md", FIX),
+    ("instructions/sdd-tweak.md", TWEAK),
+    ("instructions/sdd-analyze.md", ANALYZE),
+    ("instructions/sdd-review-code.md", REVIEW_CODE),
+    ("instructions/sdd-check-task.md", CHECK_TASK),
+    ("instructions/sdd-queue-tweak.md", QUEUE_TWEAK),
+    ("instructions/sdd-queue-fix.md", QUEUE_FIX),
+    ("agents/context-fetcher.md", CONTEXT_FETCHER),
+    ("agents/date-checker.md", DATE_CHECKER),
+    ("agents/file-creator.md", FILE_CREATOR),
+    ("agents/git-workflow.md", GIT_WORKFLOW),
+    ("agents/test-runner.md", TEST_RUNNER),
+    ("agents/code-reviewer.md", CODE_REVIEWER),
+    ("scripts/sdd-run.sh", RUN_SCRIPT),
+    ("scripts/sdd-execute-task.sh", EXECUTE_TASK_SCRIPT),
+    ("scripts/sdd-fix.sh", FIX_SCRIPT),
+    ("scripts/sdd-tweak.sh", TWEAK_SCRIPT),
+    ("scripts/sdd-check-task.sh", CHECK_TASK_SCRIPT),
+    ("scripts/sdd-queue-fix.sh", QUEUE_FIX_SCRIPT),
+    ("scripts/sdd-queue-tweak.sh", QUEUE_TWEAK_SCRIPT),
+    ("scripts/sdd-review-code.sh", REVIEW_CODE_SCRIPT),
+];
+
+const TECH_STACK: &str = r#"# Tech Stack
+# ✅ Update this file with your project’s specific tools and versions after integrating a theme via /sdd-apply-theme
+- Frontend: React (update version), TypeScript (update version)
+- Backend: None (standalone components)
+- Styling: Tailwind CSS (update version), shadcn/ui (update version)
+- Testing: Jest (optional, requires setup; update if using another framework)
+"#;
+
+const CODE_STYLE: &str = r#"# Code Style
+# ❌ Leave as default unless your team requires different naming or formatting conventions
+- Naming: camelCase for variables, PascalCase for components
+- Formatting: 2-space indentation, Prettier enforced
+- Comments: JSDoc for functions, inline for clarity
+"#;
+
+const BEST_PRACTICES: &str = r#"# Best Practices
+# ❌ Retain default practices unless your workflow demands changes
+- Keep code simple and readable
+- DRY: Extract repeated logic to components/utils
+- Use TDD for critical components
+- Commit messages: "[type]: [description] (task-id)"
+- Accessibility: WCAG 2.1 AA compliance
+"#;
+
+const THEME_STANDARDS: &str = r#"# Theme Standards
+# ✅ Customize this file with theme-specific standards after running /sdd-apply-theme
+"#;
+
+const PLAN_PRODUCT: &str = r#"# /sdd-plan-product
+Create or update product documentation:
+1. Prompt user for mission, target users, key features (min 3).
+2. Update .agent-sdd/product/overview.md with mission, users, goals.
+3. Add new phase to .agent-sdd/product/roadmap.md (3-7 features, effort: XS=1 day, S=2-3 days, M=1 week).
+4. Log decisions in .agent-sdd/product/decisions.md (ID: DEC-XXX).
+5. Reference .agent-sdd/standards/* for context.
+"#;
+
+const CREATE_SPEC: &str = r#"# /sdd-create-spec [--lite | --ui-only]
+Create a Software Design Document:
+1. Prompt user for feature name and description.
+2. If "what's next?", check .agent-sdd/product/roadmap.md for next item.
+3. Use date-checker agent to get current date, then create folder .agent-sdd/specs/create-spec-[task-id]-[CURRENT-DATE]/ (where task-id is kebab-case, max 5 words).
+4. Generate sdd.md with:
+   - Overview: Goal, user story, success criteria
+   - Technical Specs: UI requirements (skip if --lite)
+   - Tasks: List with IDs, dependencies, effort (XS=1 day, S=2-3 days, M=1 week)
+   - Test Scenarios
+   - Theme Standards Compliance (reference .agent-sdd/standards/theme-standards.md)
+5. Create tasks.json with task details.
+6. If --lite, include only Overview and Tasks.
+7. If --ui-only, focus on UI Requirements and Theme Standards.
+8. Check alignment with .agent-sdd/product/*; update decisions.md if needed.
+9. Prompt user: "Proceed with Task 1? (yes/no)"
+"#;
+
+const EXECUTE_TASK: &str = r#"# /sdd-execute-task <task-id>
+Execute a task:
+1. Load task from .agent-sdd/specs/*/tasks.json.
+2. Reference .agent-sdd/standards/theme-standards.md for style compliance.
+3. Write tests (TDD) if applicable.
+4. Implement code, commit with message "[task-id]: [description]".
+5. Run task-specific tests (if package.json exists).
+6. Use Agent-SDD instruction: /sdd-review-code on modified files (NOT bash command) to ensure UX/UI compliance.
+7. Update task status to "completed" and set ux_ui_reviewed: true in tasks.json.
+8. Notify user with summary: tasks completed, issues, commit hash.
+"#;
+
+const FIX: &str = r#"# /sdd-fix [--no-tests] <description>
+Apply a quick fix:
+1. Use date-checker agent to get current date, then create task in .agent-sdd/specs/fix-[task-id]-[CURRENT-DATE]/tasks.json.
+2. Implement fix, ensuring .agent-sdd/standards/theme-standards.md compliance.
+3. Use Agent-SDD instruction: /sdd-review-code on modified files (NOT bash command) to ensure UX/UI compliance.
+4. Commit with message "fix: [description]".
+5. Update task status to "completed" and set ux_ui_reviewed: true.
+6. Notify user with summary.
+"#;
+
+const TWEAK: &str = r#"# /sdd-tweak [--fix-style] [--no-tests] <description>
+Apply a minor UI tweak:
+1. Use date-checker agent to get current date, then create task in .agent-sdd/specs/tweak-[task-id]-[CURRENT-DATE]/tasks.json.
+2. Implement tweak, strictly following .agent-sdd/standards/theme-standards.md.
+3. Use Agent-SDD instruction: /sdd-review-code on modified files (NOT bash command) to ensure UX/UI compliance.
+4. Commit with message "tweak: [description]".
+5. Update task status to "completed" and set ux_ui_reviewed: true.
+6. Notify user with summary.
+"#;
+
+const ANALYZE: &str = r#"# /sdd-analyze
+Analyze project state:
+1. Review .agent-sdd/product/roadmap.md for progress.
+2. Check .agent-sdd/specs/*/tasks.json for completed tasks.
+3. Analyze codebase for theme compliance.
+4. Update .agent-sdd/product/decisions.md with insights.
+5. Suggest next steps based on roadmap.
+6. Notify user with summary: completed features, next tasks.
+"#;
+
+const REVIEW_CODE: &str = r#"# /sdd-review-code <file-path>
+Review code for theme standards compliance:
+1. Read .agent-sdd/standards/theme-standards.md for rules.
+2. Scan <file-path> for non-compliant styles (colors, typography, components).
+3. Apply fixes (e.g., replace invalid Tailwind classes).
+4. Commit with message "style: Update [file-path] for theme compliance".
+5. Notify user with report: issues found, fixes applied.
+"#;
+
+const CHECK_TASK: &str = r#"# /sdd-check-task <task-id>
+Verify task completion and documentation:
+1. Locate task in .agent-sdd/specs/*/tasks.json.
+2. Verify folder naming: <action>-<task-id>-[date].
+3. Check status: "completed" and ux_ui_reviewed: true.
+4. Validate documentation in sdd.md.
+5. Check theme compliance of modified files.
+6. Look for commit with task ID.
+7. Generate check report with overall status.
+"#;
+
+const QUEUE_TWEAK: &str = r#"# /sdd-queue-tweak <task-id> <description>
+Queue UI/UX improvement for batch processing:
+1. Check if task exists in specs.
+2. Use date-checker for current date.
+3. Create/update spec: queue-tweak-[task-id]-[date].
+4. Add to tweaks-queue.json sorted by priority.
+5. Estimate effort (XS/S/M).
+6. Show queue position and next steps.
+"#;
+
+const QUEUE_FIX: &str = r#"# /sdd-queue-fix <task-id> <description>
+Queue bug/issue for systematic resolution:
+1. Categorize by severity and type.
+2. Use date-checker for current date.
+3. Create/update spec: queue-fix-[task-id]-[date].
+4. Add to fixes-queue.json sorted by severity.
+5. Link to original task if regression.
+6. Generate fix ticket with priority score.
+"#;
+
+const CONTEXT_FETCHER: &str = r#"---
+name: context-fetcher
+description: Retrieves relevant information from Agent-SDD documentation files.
+tools: Read, Grep, Glob
+color: blue
+---
+
+You are a specialized information retrieval agent for Agent-SDD workflows. Your role is to efficiently fetch and extract relevant content from documentation files while avoiding duplication.
+
+## Core Responsibilities
+1. **Context Check First**: Determine if requested information is already in the main agent's context.
+2. **Selective Reading**: Extract only the specific sections or information requested.
+3. **Smart Retrieval**: Use grep to find relevant sections rather than reading entire files.
+4. **Return Efficiently**: Provide only new information not already in context.
+
+## Supported File Types
+- Specs: sdd.md, tasks.json
+- Product docs: overview.md, roadmap.md, decisions.md
+- Standards: theme-standards.md, code-style.md, best-practices.md
+
+## Workflow
+1. Check if the requested information is in context.
+2. If not, locate the requested file(s).
+3. Extract relevant sections using grep.
+4. Return specific information needed.
+
+## Output Format
+For new information:
+```
+📄 Retrieved from [file-path]
+[Extracted content]
+```
+For already-in-context:
+```
+✓ Already in context: [brief description]
+```
+
+## Constraints
+- Never return duplicated information.
+- Extract minimal necessary content.
+- Use grep for targeted searches.
+- Never modify files.
+"#;
+
+const DATE_CHECKER: &str = r#"---
+name: date-checker
+description: Determines today's date in YYYY-MM-DD format using file system timestamps.
+tools: Read, Grep, Glob
+color: pink
+---
+
+You are a specialized date determination agent for Agent-SDD workflows. Your role is to accurately determine the current date in YYYY-MM-DD format.
+
+## Core Responsibilities
+1. **Context Check**: Verify if the date is already in context.
+2. **File System Method**: Use temporary file creation to extract timestamps.
+3. **Format Validation**: Ensure YYYY-MM-DD format.
+4. **Output Clearly**: Output date at the end of response.
+
+## Workflow
+1. Check if date is in context.
+2. If not, create temporary file: `.agent-sdd/specs/.date-check`.
+3. Extract timestamp and parse to YYYY-MM-DD.
+4. Clean up temporary file.
+5. Validate format and output.
+
+## Output Format
+```
+✓ Date already in context: YYYY-MM-DD
+Today's date: YYYY-MM-DD
+```
+or
+```
+📅 Determining current date...
+✓ Date extracted: YYYY-MM-DD
+Today's date: YYYY-MM-DD
+```
+
+## Constraints
+- Always output date as: `Today's date: YYYY-MM-DD`.
+- Never ask user for date unless method fails.
+- Clean up temporary files.
+"#;
+
+const FILE_CREATOR: &str = r#"---
+name: file-creator
+description: Creates files and directories for Agent-SDD workflows.
+tools: Write, Bash, Read
+color: green
+---
+
+You are a specialized file creation agent for Agent-SDD projects. Your role is to create files and directories with consistent templates.
+
+## Core Responsibilities
+1. **Directory Creation**: Create proper directory structures.
+2. **File Generation**: Create files with headers and metadata.
+3. **Template Application**: Apply standard templates.
+4. **Naming Conventions**: Ensure proper naming.
+
+## Templates
+### sdd.md
+```markdown
+# Software Design Document: [FEATURE_NAME]
+**Created**: [CURRENT_DATE]
+**Status**: Draft
+## Overview
+[OVERVIEW_CONTENT]
+## Tasks
+[TASKS_CONTENT]
+```
+
+### tasks.json
+```json
+{
+  "feature": "[FEATURE_NAME]",
+  "tasks": []
+}
+```
+
+## Workflow
+1. Create directories with `mkdir -p`.
+2. Apply template with provided content.
+3. Use date from date-checker (YYYY-MM-DD).
+4. Report success or errors.
+
+## Output Format
+```
+✓ Created directory: [path]
+✓ Created file: [file]
+```
+or
+```
+⚠️ File already exists: [path]
+→ Action: Skipping file creation
+```
+
+## Constraints
+- Never overwrite existing files.
+- Use relative paths from project root.
+- Maintain template structure.
+"#;
+
+const GIT_WORKFLOW: &str = r#"---
+name: git-workflow
+description: Handles git operations for Agent-SDD workflows.
+tools: Bash, Read, Grep
+color: orange
+---
+
+You are a specialized git workflow agent for Agent-SDD projects. Your role is to handle git operations efficiently.
+
+## Core Responsibilities
+1. **Branch Management**: Create/switch branches (kebab-case, no dates).
+2. **Commit Operations**: Stage and commit with descriptive messages.
+3. **Status Checking**: Monitor git status.
+
+## Workflow
+1. Check current branch.
+2. Stage changes and commit with "[task-id]: [description]".
+3. Push to remote (if applicable).
+
+## Output Format
+```
+✓ Committed changes: [message]
+✓ Pushed to origin/[branch]
+```
+or
+```
+⚠️ Uncommitted changes detected
+→ Action: Staging all changes
+```
+
+## Constraints
+- Never force push without permission.
+- Check for uncommitted changes before switching branches.
+- Verify remote exists before pushing.
+"#;
+
+const TEST_RUNNER: &str = r#"---
+name: test-runner
+description: Runs tests and analyzes failures for Agent-SDD tasks.
+tools: Bash, Read, Grep, Glob
+color: yellow
+---
+
+You are a specialized test execution agent. Your role is to run tests and provide failure analysis.
+
+## Core Responsibilities
+1. **Run Tests**: Execute specified tests.
+2. **Analyze Failures**: Provide actionable failure information.
+3. **Return Control**: Never attempt fixes.
+
+## Workflow
+1. Check for package.json to run tests.
+2. Parse test results.
+3. Report failures with test name, expected vs. actual, and fix suggestion.
+
+## Output Format
+```
+✅ Passing: X tests
+❌ Failing: Y tests
+Failed Test: [test_name]
+Expected: [description]
+Actual: [description]
+Fix location: [file]
+Suggested approach: [suggestion]
+```
+
+## Constraints
+- Run only specified tests.
+- Keep analysis concise.
+- Never modify files.
+"#;
+
+const CODE_REVIEWER: &str = r#"---
+name: code-reviewer
+description: Reviews code for UX/UI compliance with theme standards.
+tools: Read, Grep, Write, Glob
+color: purple
+---
+
+You are a specialized UX/UI code review agent for Agent-SDD projects. Your role is to crawl code files, verify compliance with `.agent-sdd/standards/theme-standards.md`, and update styling.
+
+## Core Responsibilities
+1. **Code Crawling**: Scan `*.tsx` and `*.css` in `components/` and `app/`.
+2. **Theme Compliance**: Check colors, typography, components, spacing.
+3. **Accessibility**: Ensure WCAG 2.1 AA, ARIA labels, touch targets.
+4. **Responsive Design**: Verify responsive Tailwind classes.
+5. **Animations**: Add subtle animations (e.g., `animate-in`).
+6. **Styling Updates**: Apply fixes for non-compliant styles.
+7. **Reporting**: Provide report of issues and fixes.
+
+## Workflow
+1. Identify files with glob.
+2. Check compliance against theme-standards.md.
+3. Apply fixes (e.g., replace invalid classes).
+4. Commit with "style: Update [file] for theme compliance".
+5. Report issues and fixes.
+
+## Output Format
+```
+📝 Reviewing [file-path]...
+✓ Compliant: [e.g., Uses Roboto font]
+❌ Non-compliant:
+  - Issue: Uses bg-blue-500
+  - Fix: Replaced with bg-gray-500
+✓ File updated: [file-path]
+```
+
+## Constraints
+- Never modify logic/functionality.
+- Backup files as `.bak`.
+- Run automatically after /sdd-execute-task, /sdd-fix, /sdd-tweak.
+"#;
+
+// Shared by the command scripts: hands an instruction file and its arguments
+// to the Claude Code CLI from the project root
+const RUN_SCRIPT: &str = r#"#!/bin/bash
+# Runs an Agent-SDD instruction through Claude Code
+# Usage: sdd-run.sh <command> [arguments...]
+set -euo pipefail
+
+COMMAND="${1:?Usage: sdd-run.sh <command> [arguments...]}"
+shift
+
+cd "$(dirname "$0")/../.."
+INSTRUCTION=".agent-sdd/instructions/${COMMAND}.md"
+
+if [ ! -f "$INSTRUCTION" ]; then
+  echo "❌ Instruction not found: $INSTRUCTION" >&2
+  exit 1
+fi
+
+if ! command -v claude >/dev/null 2>&1; then
+  echo "❌ Claude Code CLI not found. Install it and make sure 'claude' is on your PATH." >&2
+  exit 127
+fi
+
+echo "🚀 /${COMMAND} $*"
+claude -p "$(cat "$INSTRUCTION")
+
+Arguments: $*"
+"#;
+
+const EXECUTE_TASK_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-execute-task <task-id>
+exec "$(dirname "$0")/sdd-run.sh" sdd-execute-task "$@"
+"#;
+
+const FIX_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-fix [--no-tests] <description>
+exec "$(dirname "$0")/sdd-run.sh" sdd-fix "$@"
+"#;
+
+const TWEAK_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-tweak [--fix-style] [--no-tests] <description>
+exec "$(dirname "$0")/sdd-run.sh" sdd-tweak "$@"
+"#;
+
+const CHECK_TASK_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-check-task <task-id>
+exec "$(dirname "$0")/sdd-run.sh" sdd-check-task "$@"
+"#;
+
+const QUEUE_FIX_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-queue-fix <task-id> <description>
+exec "$(dirname "$0")/sdd-run.sh" sdd-queue-fix "$@"
+"#;
+
+const QUEUE_TWEAK_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-queue-tweak <task-id> <description>
+exec "$(dirname "$0")/sdd-run.sh" sdd-queue-tweak "$@"
+"#;
+
+const REVIEW_CODE_SCRIPT: &str = r#"#!/bin/bash
+# /sdd-review-code <file-path>
+exec "$(dirname "$0")/sdd-run.sh" sdd-review-code "$@"
+"#;
@@ -0,0 +1,685 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::commands::write_file_atomic;
+use crate::tasks::{load_tasks_file, save_tasks_file, TaskRecord};
+
+// Key in a task's extra fields holding the link to its issue
+pub(crate) const LINK_FIELD: &str = "issue_link";
+const IN_PROGRESS_LABEL: &str = "in-progress";
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Issue {
+    pub(crate) number: u64,
+    pub(crate) title: String,
+    #[serde(default)]
+    pub(crate) body: String,
+    // "open" or "closed"
+    pub(crate) state: String,
+    #[serde(default)]
+    pub(crate) labels: Vec<String>,
+    #[serde(default)]
+    pub(crate) url: String,
+    #[serde(default)]
+    pub(crate) updated_at: String,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct IssueUpdate {
+    pub(crate) title: Option<String>,
+    pub(crate) state: Option<String>,
+    pub(crate) labels: Option<Vec<String>>,
+}
+
+// An issue tracker that tasks can be mirrored to. Implementations are called
+// from a blocking thread, so they may do synchronous I/O.
+pub(crate) trait IssueTrackerAdapter: Send {
+    // Identifies the tracker instance in link metadata, e.g. "github:owner/repo"
+    fn tracker_id(&self) -> String;
+    fn list_issues(&self) -> Result<Vec<Issue>, String>;
+    fn create_issue(&self, title: &str, body: &str, state: &str, labels: &[String]) -> Result<Issue, String>;
+    fn update_issue(&self, number: u64, update: &IssueUpdate) -> Result<Issue, String>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrackerConfig {
+    Github {
+        owner: String,
+        repo: String,
+        // Falls back to the GITHUB_TOKEN environment variable
+        token: Option<String>,
+        api_url: Option<String>,
+    },
+    // JSON file holding issues, for trying out sync without a real tracker
+    File { path: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SyncDirection {
+    Both,
+    Push,
+    Pull,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConflictStrategy {
+    Report,
+    PreferLocal,
+    PreferRemote,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncReport {
+    tracker: String,
+    dry_run: bool,
+    applied: bool,
+    created: Vec<SyncChange>,
+    pushed: Vec<SyncChange>,
+    pulled: Vec<SyncChange>,
+    conflicts: Vec<SyncConflict>,
+    unchanged: usize,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncChange {
+    task_id: String,
+    issue_number: Option<u64>,
+    field: String,
+    from: String,
+    to: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncConflict {
+    task_id: String,
+    issue_number: u64,
+    field: String,
+    // Value both sides agreed on at the last sync, if they were synced before
+    base: Option<String>,
+    local: String,
+    remote: String,
+}
+
+// What to do with one field of a linked task/issue pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Resolution {
+    InSync,
+    Push,
+    Pull,
+    Conflict,
+    Skip,
+}
+
+// Mirrors a spec's tasks to issues. A task is matched to its issue through the
+// link recorded in its extra fields, or failing that through the marker in the
+// issue body or an issue title starting with "[TASK-ID]". Title and status are
+// compared against the values recorded at the last sync, so a field changed on
+// only one side is copied to the other and a field changed on both sides is a
+// conflict, resolved according to `conflict_strategy`.
+#[tauri::command]
+pub async fn sync_issues(
+    spec_path: String,
+    tracker: TrackerConfig,
+    direction: Option<String>,
+    conflict_strategy: Option<String>,
+    create_missing: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<SyncReport, String> {
+    let direction = match direction.as_deref().unwrap_or("both") {
+        "both" => SyncDirection::Both,
+        "push" => SyncDirection::Push,
+        "pull" => SyncDirection::Pull,
+        other => return Err(format!("Unknown sync direction '{}': expected both, push or pull", other)),
+    };
+    let strategy = match conflict_strategy.as_deref().unwrap_or("report") {
+        "report" => ConflictStrategy::Report,
+        "prefer_local" => ConflictStrategy::PreferLocal,
+        "prefer_remote" => ConflictStrategy::PreferRemote,
+        other => return Err(format!(
+            "Unknown conflict strategy '{}': expected report, prefer_local or prefer_remote",
+            other
+        )),
+    };
+    let adapter = create_adapter(tracker)?;
+    let options = SyncOptions {
+        direction,
+        strategy,
+        create_missing: create_missing.unwrap_or(false),
+        dry_run: dry_run.unwrap_or(true),
+    };
+    
+    tokio::task::spawn_blocking(move || sync_spec(Path::new(&spec_path), adapter.as_ref(), options))
+        .await
+        .map_err(|e| format!("Issue sync failed: {}", e))?
+}
+
+fn create_adapter(config: TrackerConfig) -> Result<Box<dyn IssueTrackerAdapter>, String> {
+    match config {
+        TrackerConfig::Github { owner, repo, token, api_url } => {
+            let token = token
+                .filter(|token| !token.is_empty())
+                .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+                .ok_or("A GitHub token is required (pass one or set GITHUB_TOKEN)")?;
+            Ok(Box::new(GithubAdapter::new(owner, repo, token, api_url)?))
+        }
+        TrackerConfig::File { path } => Ok(Box::new(FileAdapter { path: PathBuf::from(path) })),
+    }
+}
+
+struct SyncOptions {
+    direction: SyncDirection,
+    strategy: ConflictStrategy,
+    create_missing: bool,
+    dry_run: bool,
+}
+
+fn sync_spec(spec_dir: &Path, adapter: &dyn IssueTrackerAdapter, options: SyncOptions) -> Result<SyncReport, String> {
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let spec_id = spec_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("")
+        .to_string();
+    let tracker_id = adapter.tracker_id();
+    let issues = adapter.list_issues()?;
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let now = chrono::Utc::now().to_rfc3339();
+    
+    let mut report = SyncReport {
+        tracker: tracker_id.clone(),
+        dry_run: options.dry_run,
+        applied: false,
+        created: Vec::new(),
+        pushed: Vec::new(),
+        pulled: Vec::new(),
+        conflicts: Vec::new(),
+        unchanged: 0,
+        errors: Vec::new(),
+    };
+    
+    let by_number: HashMap<u64, &Issue> = issues.iter().map(|issue| (issue.number, issue)).collect();
+    let mut claimed: Vec<u64> = tasks_file.tasks.iter()
+        .filter_map(|task| read_link(task, &tracker_id).map(|link| link.number))
+        .collect();
+    
+    for task in tasks_file.tasks.iter_mut() {
+        let link = read_link(task, &tracker_id);
+        let issue = match &link {
+            Some(link) => by_number.get(&link.number).copied(),
+            None => find_issue(&issues, &claimed, &spec_id, &task.id),
+        };
+        
+        let issue = match (issue, &link) {
+            (Some(issue), _) => issue,
+            (None, Some(link)) => {
+                report.errors.push(format!(
+                    "Task '{}' is linked to issue #{}, which no longer exists",
+                    task.id, link.number
+                ));
+                continue;
+            }
+            (None, None) => {
+                if options.create_missing && options.direction != SyncDirection::Pull {
+                    create_for_task(task, &spec_id, adapter, &tracker_id, &options, &now, &mut report);
+                } else {
+                    report.unchanged += 1;
+                }
+                continue;
+            }
+        };
+        claimed.push(issue.number);
+        
+        let local_title = task.name.clone();
+        let remote_title = strip_task_prefix(&issue.title, &task.id);
+        let local_status = task.status.clone();
+        let remote_status = issue_status(issue);
+        let base = link.as_ref();
+        
+        let title = resolve(base.map(|l| l.synced_title.as_str()), &local_title, &remote_title, &options);
+        let status = resolve(base.map(|l| l.synced_status.as_str()), &local_status, &remote_status, &options);
+        
+        let mut update = IssueUpdate::default();
+        let mut synced_title = base.map(|l| l.synced_title.clone()).unwrap_or_default();
+        let mut synced_status = base.map(|l| l.synced_status.clone()).unwrap_or_default();
+        let (pushed_before, pulled_before) = (report.pushed.len(), report.pulled.len());
+        
+        for (field, resolution, synced, local, remote) in [
+            ("title", title, base.map(|l| &l.synced_title), &local_title, &remote_title),
+            ("status", status, base.map(|l| &l.synced_status), &local_status, &remote_status),
+        ] {
+            let change = |from: &String, to: &String| SyncChange {
+                task_id: task.id.clone(),
+                issue_number: Some(issue.number),
+                field: field.to_string(),
+                from: from.clone(),
+                to: to.clone(),
+            };
+            match resolution {
+                Resolution::Push => report.pushed.push(change(remote, local)),
+                Resolution::Pull => report.pulled.push(change(local, remote)),
+                Resolution::Conflict => report.conflicts.push(SyncConflict {
+                    task_id: task.id.clone(),
+                    issue_number: issue.number,
+                    field: field.to_string(),
+                    base: synced.cloned(),
+                    local: local.clone(),
+                    remote: remote.clone(),
+                }),
+                Resolution::InSync | Resolution::Skip => {}
+            }
+        }
+        
+        if [title, status].iter().all(|r| matches!(r, Resolution::InSync | Resolution::Skip)) {
+            report.unchanged += 1;
+        }
+        if options.dry_run {
+            continue;
+        }
+        
+        // Push first: if the issue can't be updated the task is left as it was,
+        // pulled changes included, so the next sync sees the same picture
+        if title == Resolution::Push {
+            update.title = Some(format_issue_title(&task.id, &local_title));
+        }
+        if status == Resolution::Push {
+            let (state, labels) = issue_state_for(&local_status, &issue.labels);
+            update.state = Some(state);
+            update.labels = Some(labels);
+        }
+        if update.title.is_some() || update.state.is_some() {
+            if let Err(e) = adapter.update_issue(issue.number, &update) {
+                report.errors.push(format!("Failed to update issue #{} for task '{}': {}", issue.number, task.id, e));
+                report.pushed.truncate(pushed_before);
+                report.pulled.truncate(pulled_before);
+                continue;
+            }
+        }
+        
+        if title == Resolution::Pull {
+            task.name = remote_title.clone();
+        }
+        if status == Resolution::Pull {
+            set_task_status(task, &remote_status, &today);
+        }
+        
+        if matches!(title, Resolution::InSync | Resolution::Push | Resolution::Pull) {
+            synced_title = task.name.clone();
+        }
+        if matches!(status, Resolution::InSync | Resolution::Push | Resolution::Pull) {
+            synced_status = task.status.clone();
+        }
+        write_link(task, &tracker_id, issue.number, &issue.url, &synced_title, &synced_status, &now);
+    }
+    
+    if !options.dry_run {
+        save_tasks_file(spec_dir, &tasks_file)?;
+        report.applied = true;
+    }
+    
+    Ok(report)
+}
+
+fn create_for_task(
+    task: &mut TaskRecord,
+    spec_id: &str,
+    adapter: &dyn IssueTrackerAdapter,
+    tracker_id: &str,
+    options: &SyncOptions,
+    now: &str,
+    report: &mut SyncReport,
+) {
+    let title = format_issue_title(&task.id, &task.name);
+    let created = |issue_number: Option<u64>| SyncChange {
+        task_id: task.id.clone(),
+        issue_number,
+        field: "issue".to_string(),
+        from: String::new(),
+        to: title.clone(),
+    };
+    if options.dry_run {
+        report.created.push(created(None));
+        return;
+    }
+    
+    let body = format!("{}\n\n{}", task.description, task_marker(spec_id, &task.id));
+    let (state, labels) = issue_state_for(&task.status, &[]);
+    match adapter.create_issue(&title, body.trim_start(), &state, &labels) {
+        Ok(issue) => {
+            report.created.push(created(Some(issue.number)));
+            let (name, status) = (task.name.clone(), task.status.clone());
+            write_link(task, tracker_id, issue.number, &issue.url, &name, &status, now);
+        }
+        Err(e) => report.errors.push(format!("Failed to create issue for task '{}': {}", task.id, e)),
+    }
+}
+
+fn resolve(base: Option<&str>, local: &str, remote: &str, options: &SyncOptions) -> Resolution {
+    if local == remote {
+        return Resolution::InSync;
+    }
+    
+    let local_changed = base != Some(local);
+    let remote_changed = base != Some(remote);
+    let resolution = match (local_changed, remote_changed) {
+        (true, false) => Resolution::Push,
+        (false, true) => Resolution::Pull,
+        _ => match options.strategy {
+            ConflictStrategy::Report => Resolution::Conflict,
+            ConflictStrategy::PreferLocal => Resolution::Push,
+            ConflictStrategy::PreferRemote => Resolution::Pull,
+        },
+    };
+    
+    match (resolution, options.direction) {
+        (Resolution::Push, SyncDirection::Pull) | (Resolution::Pull, SyncDirection::Push) => Resolution::Skip,
+        (resolution, _) => resolution,
+    }
+}
+
+struct IssueLink {
+    number: u64,
+    synced_title: String,
+    synced_status: String,
+}
+
+fn read_link(task: &TaskRecord, tracker_id: &str) -> Option<IssueLink> {
+    let link = task.extra.get(LINK_FIELD)?;
+    if link["tracker"].as_str() != Some(tracker_id) {
+        return None;
+    }
+    
+    Some(IssueLink {
+        number: link["number"].as_u64()?,
+        synced_title: link["synced_title"].as_str().unwrap_or("").to_string(),
+        synced_status: link["synced_status"].as_str().unwrap_or("").to_string(),
+    })
+}
+
+fn write_link(
+    task: &mut TaskRecord,
+    tracker_id: &str,
+    number: u64,
+    url: &str,
+    synced_title: &str,
+    synced_status: &str,
+    now: &str,
+) {
+    task.extra.insert(LINK_FIELD.to_string(), json!({
+        "tracker": tracker_id,
+        "number": number,
+        "url": url,
+        "synced_title": synced_title,
+        "synced_status": synced_status,
+        "synced_at": now,
+    }));
+}
+
+// Matches an unlinked task by the marker written into issues this app creates,
+// then by an issue title of the form "[TASK-ID] ..."
+fn find_issue<'a>(issues: &'a [Issue], claimed: &[u64], spec_id: &str, task_id: &str) -> Option<&'a Issue> {
+    let marker = task_marker(spec_id, task_id);
+    let prefix = format!("[{}]", task_id);
+    let available = || issues.iter().filter(|issue| !claimed.contains(&issue.number));
+    
+    available()
+        .find(|issue| issue.body.contains(&marker))
+        .or_else(|| available().find(|issue| issue.title.trim_start().starts_with(&prefix)))
+}
+
+fn task_marker(spec_id: &str, task_id: &str) -> String {
+    format!("<!-- agent-sdd-task: {}/{} -->", spec_id, task_id)
+}
+
+fn format_issue_title(task_id: &str, name: &str) -> String {
+    format!("[{}] {}", task_id, name)
+}
+
+fn strip_task_prefix(title: &str, task_id: &str) -> String {
+    let prefix = format!("[{}]", task_id);
+    title.trim_start()
+        .strip_prefix(&prefix)
+        .unwrap_or(title)
+        .trim()
+        .to_string()
+}
+
+fn issue_status(issue: &Issue) -> String {
+    if issue.state == "closed" {
+        "completed".to_string()
+    } else if issue.labels.iter().any(|label| label == IN_PROGRESS_LABEL) {
+        "in_progress".to_string()
+    } else {
+        "pending".to_string()
+    }
+}
+
+// Issue state and labels representing a task status, keeping unrelated labels
+fn issue_state_for(status: &str, labels: &[String]) -> (String, Vec<String>) {
+    let mut labels: Vec<String> = labels.iter().filter(|label| *label != IN_PROGRESS_LABEL).cloned().collect();
+    if status == "in_progress" {
+        labels.push(IN_PROGRESS_LABEL.to_string());
+    }
+    let state = if status == "completed" { "closed" } else { "open" };
+    (state.to_string(), labels)
+}
+
+fn set_task_status(task: &mut TaskRecord, status: &str, today: &str) {
+    task.status = status.to_string();
+    if status == "completed" {
+        if task.completed.is_none() {
+            task.completed = Some(today.to_string());
+        }
+    } else {
+        task.completed = None;
+    }
+}
+
+struct GithubAdapter {
+    client: reqwest::blocking::Client,
+    api_url: String,
+    owner: String,
+    repo: String,
+    token: String,
+}
+
+impl GithubAdapter {
+    fn new(owner: String, repo: String, token: String, api_url: Option<String>) -> Result<Self, String> {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("agent-sdd-app")
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        
+        Ok(Self {
+            client,
+            api_url: api_url.unwrap_or_else(|| GITHUB_API_URL.to_string()).trim_end_matches('/').to_string(),
+            owner,
+            repo,
+            token,
+        })
+    }
+    
+    fn issues_url(&self) -> String {
+        format!("{}/repos/{}/{}/issues", self.api_url, self.owner, self.repo)
+    }
+    
+    fn send(&self, request: reqwest::blocking::RequestBuilder) -> Result<Value, String> {
+        let response = request
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .send()
+            .map_err(|e| format!("GitHub request failed: {}", e))?;
+        
+        let status = response.status();
+        let body: Value = response.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = body["message"].as_str().unwrap_or("unknown error");
+            return Err(format!("GitHub returned {}: {}", status, message));
+        }
+        Ok(body)
+    }
+    
+    fn parse_issue(value: &Value) -> Option<Issue> {
+        Some(Issue {
+            number: value["number"].as_u64()?,
+            title: value["title"].as_str()?.to_string(),
+            body: value["body"].as_str().unwrap_or("").to_string(),
+            state: value["state"].as_str().unwrap_or("open").to_string(),
+            labels: value["labels"].as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|label| label["name"].as_str().map(|name| name.to_string()))
+                .collect(),
+            url: value["html_url"].as_str().unwrap_or("").to_string(),
+            updated_at: value["updated_at"].as_str().unwrap_or("").to_string(),
+        })
+    }
+}
+
+impl IssueTrackerAdapter for GithubAdapter {
+    fn tracker_id(&self) -> String {
+        format!("github:{}/{}", self.owner, self.repo)
+    }
+    
+    fn list_issues(&self) -> Result<Vec<Issue>, String> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let body = self.send(self.client.get(self.issues_url()).query(&[
+                ("state", "all".to_string()),
+                ("per_page", "100".to_string()),
+                ("page", page.to_string()),
+            ]))?;
+            let items = body.as_array().cloned().unwrap_or_default();
+            
+            // The issues endpoint also returns pull requests
+            issues.extend(items.iter()
+                .filter(|item| item.get("pull_request").is_none())
+                .filter_map(Self::parse_issue));
+            
+            if items.len() < 100 {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+    
+    fn create_issue(&self, title: &str, body: &str, state: &str, labels: &[String]) -> Result<Issue, String> {
+        let created = self.send(self.client.post(self.issues_url()).json(&json!({
+            "title": title,
+            "body": body,
+            "labels": labels,
+        })))?;
+        let mut issue = Self::parse_issue(&created).ok_or("GitHub returned an unexpected issue payload")?;
+        
+        // Issues can't be created closed
+        if state != issue.state {
+            issue = self.update_issue(issue.number, &IssueUpdate {
+                state: Some(state.to_string()),
+                ..IssueUpdate::default()
+            })?;
+        }
+        Ok(issue)
+    }
+    
+    fn update_issue(&self, number: u64, update: &IssueUpdate) -> Result<Issue, String> {
+        let mut patch = Map::new();
+        if let Some(title) = &update.title {
+            patch.insert("title".to_string(), json!(title));
+        }
+        if let Some(state) = &update.state {
+            patch.insert("state".to_string(), json!(state));
+        }
+        if let Some(labels) = &update.labels {
+            patch.insert("labels".to_string(), json!(labels));
+        }
+        
+        let url = format!("{}/{}", self.issues_url(), number);
+        let updated = self.send(self.client.patch(url).json(&Value::Object(patch)))?;
+        Self::parse_issue(&updated).ok_or_else(|| "GitHub returned an unexpected issue payload".to_string())
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct IssueStore {
+    #[serde(default)]
+    issues: Vec<Issue>,
+}
+
+// Keeps issues in a local JSON file; edit the file by hand to simulate changes
+// made on the tracker side
+struct FileAdapter {
+    path: PathBuf,
+}
+
+impl FileAdapter {
+    fn load(&self) -> Result<IssueStore, String> {
+        if !self.path.exists() {
+            return Ok(IssueStore::default());
+        }
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Failed to read issue file: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse issue file: {}", e))
+    }
+    
+    fn save(&self, store: &IssueStore) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(store)
+            .map_err(|e| format!("Failed to serialize issue file: {}", e))?;
+        write_file_atomic(&self.path, &content)
+    }
+}
+
+impl IssueTrackerAdapter for FileAdapter {
+    fn tracker_id(&self) -> String {
+        format!("file:{}", self.path.display())
+    }
+    
+    fn list_issues(&self) -> Result<Vec<Issue>, String> {
+        Ok(self.load()?.issues)
+    }
+    
+    fn create_issue(&self, title: &str, body: &str, state: &str, labels: &[String]) -> Result<Issue, String> {
+        let mut store = self.load()?;
+        let number = store.issues.iter().map(|issue| issue.number).max().unwrap_or(0) + 1;
+        let issue = Issue {
+            number,
+            title: title.to_string(),
+            body: body.to_string(),
+            state: state.to_string(),
+            labels: labels.to_vec(),
+            url: format!("{}#{}", self.path.display(), number),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        store.issues.push(issue.clone());
+        self.save(&store)?;
+        Ok(issue)
+    }
+    
+    fn update_issue(&self, number: u64, update: &IssueUpdate) -> Result<Issue, String> {
+        let mut store = self.load()?;
+        let issue = store.issues.iter_mut()
+            .find(|issue| issue.number == number)
+            .ok_or_else(|| format!("Issue #{} not found", number))?;
+        
+        if let Some(title) = &update.title {
+            issue.title = title.clone();
+        }
+        if let Some(state) = &update.state {
+            issue.state = state.clone();
+        }
+        if let Some(labels) = &update.labels {
+            issue.labels = labels.clone();
+        }
+        issue.updated_at = chrono::Utc::now().to_rfc3339();
+        
+        let updated = issue.clone();
+        self.save(&store)?;
+        Ok(updated)
+    }
+}
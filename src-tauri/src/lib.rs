@@ -1,20 +1,88 @@
+mod analytics;
+mod brief;
 mod commands;
+mod decisions;
+mod dependencies;
+mod diff;
+mod doctor;
+mod framework;
+mod git;
+mod history;
+mod init;
+mod issue_sync;
+mod lifecycle;
+mod query;
+mod report;
+mod roadmap;
+mod search;
+mod settings;
+mod specs;
+mod stale;
+mod task_exchange;
+mod tasks;
+mod templates;
+mod workspace;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
     .plugin(tauri_plugin_fs::init())
     .plugin(tauri_plugin_dialog::init())
+    .manage(search::SearchIndexCache::default())
+    .manage(workspace::WorkspaceCache::default())
+    .manage(settings::SettingsStore::default())
     .invoke_handler(tauri::generate_handler![
       commands::select_base_dir,
       commands::list_child_directories,
       commands::scan_project,
       commands::read_file,
       commands::scan_specs,
-      commands::create_agent_sdd_structure,
+      init::create_agent_sdd_structure,
+      init::repair_agent_sdd_structure,
+      framework::check_agent_sdd_version,
+      framework::upgrade_agent_sdd,
+      doctor::run_doctor,
+      doctor::apply_doctor_fix,
       commands::create_spec,
       commands::analyze_spec,
-      commands::execute_agent_sdd_command
+      commands::execute_agent_sdd_command,
+      search::search_project,
+      query::query_tasks,
+      workspace::scan_workspace,
+      workspace::discover_projects,
+      settings::get_settings,
+      settings::update_settings,
+      history::get_execution_history,
+      analytics::get_progress_analytics,
+      stale::get_stale_tasks,
+      stale::reset_stale_tasks,
+      report::export_spec_report,
+      report::export_project_report,
+      task_exchange::export_tasks,
+      task_exchange::import_tasks,
+      issue_sync::sync_issues,
+      roadmap::get_roadmap,
+      roadmap::complete_roadmap_items,
+      roadmap::append_progress_log,
+      tasks::update_task_status,
+      tasks::add_task,
+      tasks::update_task,
+      tasks::delete_task,
+      tasks::reorder_tasks,
+      decisions::list_decisions,
+      decisions::add_decision,
+      lifecycle::get_spec_status,
+      lifecycle::set_spec_status,
+      specs::archive_spec,
+      specs::restore_spec,
+      specs::rename_spec,
+      specs::duplicate_spec,
+      specs::delete_spec,
+      specs::restore_deleted_spec,
+      tasks::toggle_checklist_item,
+      dependencies::get_dependency_graph,
+      templates::list_spec_templates,
+      brief::create_spec_from_brief
     ])
     .setup(|app| {
       if cfg!(debug_assertions) {
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::tasks::{load_tasks_file, save_tasks_file, TasksFile};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecStatus {
+    Draft,
+    Planned,
+    InProgress,
+    Review,
+    Completed,
+    Archived,
+}
+
+impl SpecStatus {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+            "draft" => Some(Self::Draft),
+            // Specs created before the lifecycle existed start out "pending"
+            "planned" | "pending" => Some(Self::Planned),
+            "in_progress" => Some(Self::InProgress),
+            "review" | "in_review" => Some(Self::Review),
+            "completed" | "done" => Some(Self::Completed),
+            "archived" => Some(Self::Archived),
+            _ => None,
+        }
+    }
+    
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Planned => "planned",
+            Self::InProgress => "in_progress",
+            Self::Review => "review",
+            Self::Completed => "completed",
+            Self::Archived => "archived",
+        }
+    }
+    
+    // Forward one step, back to an earlier working state, or out to the archive
+    pub(crate) fn allowed_transitions(self) -> &'static [SpecStatus] {
+        use SpecStatus::*;
+        match self {
+            Draft => &[Planned, Archived],
+            Planned => &[Draft, InProgress, Archived],
+            InProgress => &[Planned, Review, Archived],
+            Review => &[InProgress, Completed, Archived],
+            Completed => &[InProgress, Review, Archived],
+            Archived => &[Draft, Planned, InProgress, Review, Completed],
+        }
+    }
+}
+
+impl fmt::Display for SpecStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpecStatusReport {
+    // Status as written in tasks.json
+    status: String,
+    // Status the task list points to
+    derived: SpecStatus,
+    allowed_transitions: Vec<SpecStatus>,
+    warnings: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn get_spec_status(spec_path: String) -> Result<SpecStatusReport, String> {
+    let tasks_file = load_tasks_file(Path::new(&spec_path))?;
+    Ok(status_report(&tasks_file))
+}
+
+// Moves a spec to a new lifecycle status. Transitions outside the lifecycle are
+// refused unless `force` is set; a status that disagrees with the tasks is
+// allowed but reported in the warnings.
+#[tauri::command]
+pub async fn set_spec_status(spec_path: String, status: String, force: Option<bool>) -> Result<SpecStatusReport, String> {
+    let target = SpecStatus::parse(&status).ok_or_else(|| format!(
+        "Unknown spec status '{}' (expected one of draft, planned, in_progress, review, completed, archived)",
+        status
+    ))?;
+    
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let current = tasks_file.status.as_deref().and_then(SpecStatus::parse);
+    
+    if let Some(current) = current {
+        if current != target && !current.allowed_transitions().contains(&target) && !force.unwrap_or(false) {
+            return Err(format!(
+                "Cannot move spec from '{}' to '{}' (allowed: {})",
+                current,
+                target,
+                current.allowed_transitions().iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ")
+            ));
+        }
+    }
+    
+    tasks_file.status = Some(target.as_str().to_string());
+    save_tasks_file(spec_dir, &tasks_file)?;
+    
+    Ok(status_report(&tasks_file))
+}
+
+fn status_report(tasks_file: &TasksFile) -> SpecStatusReport {
+    let task_statuses: Vec<&str> = tasks_file.tasks.iter().map(|task| task.status.as_str()).collect();
+    let status = tasks_file.status.clone().unwrap_or_default();
+    
+    SpecStatusReport {
+        allowed_transitions: SpecStatus::parse(&status)
+            .map(|current| current.allowed_transitions().to_vec())
+            .unwrap_or_default(),
+        derived: derive_status(&task_statuses),
+        warnings: status_warnings(&status, &task_statuses),
+        status,
+    }
+}
+
+// The furthest status the tasks alone justify. Review is as far as derivation
+// goes: marking a spec completed is a human decision.
+pub(crate) fn derive_status(task_statuses: &[&str]) -> SpecStatus {
+    let completed = task_statuses.iter().filter(|status| **status == "completed").count();
+    let started = task_statuses.contains(&"in_progress");
+    
+    if task_statuses.is_empty() {
+        SpecStatus::Draft
+    } else if completed == task_statuses.len() {
+        SpecStatus::Review
+    } else if started || completed > 0 {
+        SpecStatus::InProgress
+    } else {
+        SpecStatus::Planned
+    }
+}
+
+// Ways the declared spec status disagrees with its tasks
+pub(crate) fn status_warnings(status: &str, task_statuses: &[&str]) -> Vec<String> {
+    let declared = match SpecStatus::parse(status) {
+        Some(declared) => declared,
+        None => return vec![format!("Spec status '{}' is not a lifecycle status", status)],
+    };
+    let derived = derive_status(task_statuses);
+    let open = task_statuses.iter().filter(|status| **status != "completed").count();
+    
+    let warning = match (declared, derived) {
+        (SpecStatus::Archived, _) => None,
+        (SpecStatus::Draft | SpecStatus::Planned, SpecStatus::InProgress | SpecStatus::Review) => Some(format!(
+            "Spec status is '{}' but work has started on its tasks - set it to 'in_progress'",
+            declared
+        )),
+        (SpecStatus::InProgress, SpecStatus::Review) => Some(
+            "All tasks are completed - move the spec to 'review'".to_string()
+        ),
+        (SpecStatus::InProgress, SpecStatus::Planned) => Some(
+            "Spec status is 'in_progress' but no task has been started".to_string()
+        ),
+        (SpecStatus::Review | SpecStatus::Completed, SpecStatus::Draft | SpecStatus::Planned | SpecStatus::InProgress) => Some(format!(
+            "Spec status is '{}' but {} {} not completed",
+            declared,
+            open,
+            if open == 1 { "task is" } else { "tasks are" }
+        )),
+        _ => None,
+    };
+    
+    warning.into_iter().collect()
+}
+
+// Moves the spec status forward when task changes make the next step obvious:
+// first task started, or last task completed. Steps through the lifecycle one
+// allowed transition at a time, so drafts stay drafts until someone plans them
+// and `status_warnings` points out the mismatch. Never moves it backwards, and
+// leaves statuses outside the lifecycle alone. Returns the new status if changed.
+pub(crate) fn advance_status(tasks_file: &mut TasksFile) -> Option<SpecStatus> {
+    let current = tasks_file.status.as_deref().and_then(SpecStatus::parse)?;
+    let task_statuses: Vec<&str> = tasks_file.tasks.iter().map(|task| task.status.as_str()).collect();
+    let derived = derive_status(&task_statuses);
+    
+    let mut next = current;
+    loop {
+        let step = match (next, derived) {
+            (SpecStatus::Planned, SpecStatus::InProgress | SpecStatus::Review) => SpecStatus::InProgress,
+            (SpecStatus::InProgress, SpecStatus::Review) => SpecStatus::Review,
+            _ => break,
+        };
+        if !next.allowed_transitions().contains(&step) {
+            break;
+        }
+        next = step;
+    }
+    
+    if next == current {
+        return None;
+    }
+    tasks_file.status = Some(next.as_str().to_string());
+    Some(next)
+}
@@ -0,0 +1,484 @@
+use std::path::Path;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, SpecMetadata, TaskInfo};
+use crate::dependencies::resolve_dependency;
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 500;
+const EFFORT_SCALE: [&str; 5] = ["XS", "S", "M", "L", "XL"];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskQueryResults {
+    total: usize,
+    page: usize,
+    page_size: usize,
+    total_pages: usize,
+    items: Vec<TaskQueryItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskQueryItem {
+    project_name: String,
+    project_path: String,
+    spec_id: String,
+    spec_feature: String,
+    spec_phase: String,
+    spec_status: String,
+    spec_created: String,
+    spec_path: String,
+    task: TaskInfo,
+}
+
+impl TaskQueryItem {
+    pub(crate) fn new(project_name: &str, project_path: &str, spec: &SpecMetadata, task: &TaskInfo) -> TaskQueryItem {
+        TaskQueryItem {
+            project_name: project_name.to_string(),
+            project_path: project_path.to_string(),
+            spec_id: spec.id.clone(),
+            spec_feature: spec.feature.clone(),
+            spec_phase: spec.phase.clone(),
+            spec_status: spec.status.clone(),
+            spec_created: spec.created.clone(),
+            spec_path: spec.path.clone(),
+            task: task.clone(),
+        }
+    }
+}
+
+// Parsed form of a query such as `status:pending effort:>=S deps:ready spec:~auth`.
+// Adjacent terms are ANDed; `OR`, `NOT`/`-` and parentheses are also supported.
+#[derive(Debug, PartialEq)]
+enum QueryExpr {
+    And(Vec<QueryExpr>),
+    Or(Vec<QueryExpr>),
+    Not(Box<QueryExpr>),
+    Condition(Condition),
+    Text(String),
+}
+
+#[derive(Debug, PartialEq)]
+struct Condition {
+    field: Field,
+    op: CompareOp,
+    // Any-of values, from `status:pending,in_progress`
+    values: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Id,
+    Name,
+    Description,
+    Status,
+    Effort,
+    Deps,
+    Spec,
+    Phase,
+    SpecStatus,
+    Created,
+    Completed,
+    Reviewed,
+    Project,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    NotEq,
+    Contains,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Word(String),
+}
+
+// Everything a query can look at for a single task
+struct TaskContext<'a> {
+    project_name: &'a str,
+    spec: &'a SpecMetadata,
+    task: &'a TaskInfo,
+    // Every spec of the project, for dependencies on other specs' tasks
+    specs: &'a [SpecMetadata],
+}
+
+#[tauri::command]
+pub async fn query_tasks(
+    project_paths: Vec<String>,
+    query: String,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+) -> Result<TaskQueryResults, String> {
+    let expr = parse_task_query(&query)?;
+    let sort_key = sort_by.unwrap_or_else(|| "spec".to_string());
+    let descending = match sort_order.as_deref() {
+        None | Some("asc") => false,
+        Some("desc") => true,
+        Some(other) => return Err(format!("Unknown sort order '{}': expected 'asc' or 'desc'", other)),
+    };
+    
+    let mut items = Vec::new();
+    
+    for project_path in project_paths {
+        let project_name = Path::new(&project_path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&project_path)
+            .to_string();
+        
+        let specs = scan_specs(project_path.clone(), None).await
+            .map_err(|e| format!("Failed to scan specs in {}: {}", project_path, e))?;
+        
+        for spec in &specs {
+            for task in &spec.tasks {
+                let context = TaskContext {
+                    project_name: &project_name,
+                    spec,
+                    task,
+                    specs: &specs,
+                };
+                
+                if evaluate(&expr, &context) {
+                    items.push(TaskQueryItem::new(&project_name, &project_path, spec, task));
+                }
+            }
+        }
+    }
+    
+    sort_items(&mut items, &sort_key, descending)?;
+    
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let total = items.len();
+    let total_pages = total.div_ceil(page_size).max(1);
+    let page = page.unwrap_or(1).clamp(1, total_pages);
+    
+    let items = items.into_iter()
+        .skip((page - 1) * page_size)
+        .take(page_size)
+        .collect();
+    
+    Ok(TaskQueryResults {
+        total,
+        page,
+        page_size,
+        total_pages,
+        items,
+    })
+}
+
+fn parse_task_query(query: &str) -> Result<QueryExpr, String> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+        return Ok(QueryExpr::And(Vec::new()));
+    }
+    
+    let mut pos = 0;
+    let expr = parse_or(&tokens, &mut pos)?;
+    if pos < tokens.len() {
+        return Err("Unexpected ')' in query".to_string());
+    }
+    
+    Ok(expr)
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    
+    for c in query.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' | ')' if !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut current)));
+                }
+                tokens.push(if c == '(' { Token::LParen } else { Token::RParen });
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut current)));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    
+    if in_quotes {
+        return Err("Unterminated quote in query".to_string());
+    }
+    if !current.is_empty() {
+        tokens.push(Token::Word(current));
+    }
+    
+    Ok(tokens)
+}
+
+fn parse_or(tokens: &[Token], pos: &mut usize) -> Result<QueryExpr, String> {
+    let mut branches = vec![parse_and(tokens, pos)?];
+    
+    while matches!(tokens.get(*pos), Some(Token::Word(word)) if word == "OR") {
+        *pos += 1;
+        branches.push(parse_and(tokens, pos)?);
+    }
+    
+    Ok(if branches.len() == 1 { branches.remove(0) } else { QueryExpr::Or(branches) })
+}
+
+fn parse_and(tokens: &[Token], pos: &mut usize) -> Result<QueryExpr, String> {
+    let mut terms = Vec::new();
+    
+    loop {
+        match tokens.get(*pos) {
+            None | Some(Token::RParen) => break,
+            Some(Token::Word(word)) if word == "OR" => break,
+            Some(Token::Word(word)) if word == "AND" => *pos += 1,
+            _ => terms.push(parse_unary(tokens, pos)?),
+        }
+    }
+    
+    if terms.is_empty() {
+        return Err("Expected a search term in query".to_string());
+    }
+    
+    Ok(if terms.len() == 1 { terms.remove(0) } else { QueryExpr::And(terms) })
+}
+
+fn parse_unary(tokens: &[Token], pos: &mut usize) -> Result<QueryExpr, String> {
+    match tokens.get(*pos) {
+        Some(Token::LParen) => {
+            *pos += 1;
+            let expr = parse_or(tokens, pos)?;
+            if tokens.get(*pos) != Some(&Token::RParen) {
+                return Err("Missing ')' in query".to_string());
+            }
+            *pos += 1;
+            Ok(expr)
+        }
+        Some(Token::Word(word)) if word == "NOT" => {
+            *pos += 1;
+            Ok(QueryExpr::Not(Box::new(parse_unary(tokens, pos)?)))
+        }
+        Some(Token::Word(word)) if word.len() > 1 && word.starts_with('-') => {
+            *pos += 1;
+            Ok(QueryExpr::Not(Box::new(parse_term(&word[1..])?)))
+        }
+        Some(Token::Word(word)) => {
+            *pos += 1;
+            parse_term(word)
+        }
+        Some(Token::RParen) | None => Err("Expected a search term in query".to_string()),
+    }
+}
+
+fn parse_term(word: &str) -> Result<QueryExpr, String> {
+    let (name, raw_value) = match word.split_once(':') {
+        Some(parts) => parts,
+        None => return Ok(QueryExpr::Text(word.to_lowercase())),
+    };
+    
+    let field = match name.to_lowercase().as_str() {
+        "id" => Field::Id,
+        "name" | "title" => Field::Name,
+        "description" | "desc" => Field::Description,
+        "status" => Field::Status,
+        "effort" => Field::Effort,
+        "deps" | "dependencies" => Field::Deps,
+        "spec" => Field::Spec,
+        "phase" => Field::Phase,
+        "spec_status" => Field::SpecStatus,
+        "created" => Field::Created,
+        "completed" => Field::Completed,
+        "reviewed" => Field::Reviewed,
+        "project" => Field::Project,
+        other => return Err(format!("Unknown field '{}' in query", other)),
+    };
+    
+    let (op, value) = [
+        (">=", CompareOp::Gte),
+        ("<=", CompareOp::Lte),
+        ("!=", CompareOp::NotEq),
+        (">", CompareOp::Gt),
+        ("<", CompareOp::Lt),
+        ("~", CompareOp::Contains),
+        ("!", CompareOp::NotEq),
+        ("=", CompareOp::Eq),
+    ]
+    .iter()
+    .find_map(|(prefix, op)| raw_value.strip_prefix(prefix).map(|rest| (*op, rest)))
+    .unwrap_or((CompareOp::Eq, raw_value));
+    
+    let values: Vec<String> = value.split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+    if values.is_empty() {
+        return Err(format!("Missing value for '{}' in query", name));
+    }
+    
+    let is_ordering = matches!(op, CompareOp::Gt | CompareOp::Gte | CompareOp::Lt | CompareOp::Lte);
+    match field {
+        Field::Effort if is_ordering => {
+            for value in &values {
+                effort_rank(value)
+                    .ok_or_else(|| format!("Unknown effort '{}': expected one of {}", value, EFFORT_SCALE.join(", ")))?;
+            }
+        }
+        Field::Created | Field::Completed if is_ordering || op == CompareOp::Eq => {
+            for value in &values {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|_| format!("Invalid date '{}' for {}: expected YYYY-MM-DD", value, name))?;
+            }
+        }
+        Field::Deps if op == CompareOp::Eq => {}
+        _ if is_ordering => {
+            return Err(format!("Field '{}' does not support comparisons", name));
+        }
+        _ => {}
+    }
+    
+    Ok(QueryExpr::Condition(Condition { field, op, values }))
+}
+
+fn evaluate(expr: &QueryExpr, context: &TaskContext) -> bool {
+    match expr {
+        QueryExpr::And(terms) => terms.iter().all(|term| evaluate(term, context)),
+        QueryExpr::Or(branches) => branches.iter().any(|branch| evaluate(branch, context)),
+        QueryExpr::Not(inner) => !evaluate(inner, context),
+        QueryExpr::Text(text) => {
+            let task = context.task;
+            [&task.id, &task.name, &task.description]
+                .iter()
+                .any(|field| field.to_lowercase().contains(text.as_str()))
+        }
+        QueryExpr::Condition(condition) => {
+            let matched = condition.values.iter().any(|value| matches_value(condition, value, context));
+            // `field:!a,b` means "none of a or b", so negate after the any-of check
+            if condition.op == CompareOp::NotEq { !matched } else { matched }
+        }
+    }
+}
+
+fn matches_value(condition: &Condition, value: &str, context: &TaskContext) -> bool {
+    let task = context.task;
+    let spec = context.spec;
+    
+    match condition.field {
+        Field::Id => compare_text(&task.id, condition.op, value),
+        Field::Name => compare_text(&task.name, condition.op, value),
+        Field::Description => compare_text(&task.description, condition.op, value),
+        Field::Status => compare_text(&task.status, condition.op, value),
+        Field::Phase => compare_text(&spec.phase, condition.op, value),
+        Field::SpecStatus => compare_text(&spec.status, condition.op, value),
+        Field::Project => compare_text(context.project_name, condition.op, value),
+        Field::Spec => {
+            compare_text(&spec.id, condition.op, value) || compare_text(&spec.feature, condition.op, value)
+        }
+        Field::Effort => match condition.op {
+            CompareOp::Contains => compare_text(&task.effort, condition.op, value),
+            _ => match (effort_rank(&task.effort), effort_rank(value)) {
+                (Some(actual), Some(expected)) => compare_ordered(actual, condition.op, expected),
+                _ => compare_text(&task.effort, condition.op, value),
+            },
+        },
+        Field::Created => compare_date(Some(&spec.created), condition.op, value),
+        Field::Completed => compare_date(task.completed.as_deref(), condition.op, value),
+        Field::Reviewed => {
+            let reviewed = task.ux_ui_reviewed.unwrap_or(false);
+            match value.to_lowercase().as_str() {
+                "true" | "yes" => reviewed,
+                "false" | "no" => !reviewed,
+                _ => false,
+            }
+        }
+        Field::Deps => {
+            let dependency_done = |dep: &String| resolve_dependency(context.specs, context.spec, dep)
+                .is_ok_and(|(_, task)| task.status == "completed");
+            match value.to_lowercase().as_str() {
+                "ready" => task.dependencies.iter().all(dependency_done),
+                "blocked" => !task.dependencies.iter().all(dependency_done),
+                "none" => task.dependencies.is_empty(),
+                "any" => !task.dependencies.is_empty(),
+                _ => task.dependencies.iter().any(|dep| compare_text(dep, condition.op, value)),
+            }
+        }
+    }
+}
+
+fn compare_text(actual: &str, op: CompareOp, expected: &str) -> bool {
+    match op {
+        CompareOp::Contains => actual.to_lowercase().contains(&expected.to_lowercase()),
+        // Ordering is rejected at parse time for text fields, so only equality remains
+        _ => actual.eq_ignore_ascii_case(expected),
+    }
+}
+
+fn compare_date(actual: Option<&str>, op: CompareOp, expected: &str) -> bool {
+    let actual = match actual {
+        Some(actual) => actual,
+        None => return false,
+    };
+    
+    if op == CompareOp::Contains {
+        return actual.contains(expected);
+    }
+    
+    // Dates may carry a time component; only the day is compared
+    let actual = actual.get(..10).and_then(|day| NaiveDate::parse_from_str(day, "%Y-%m-%d").ok());
+    let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").ok();
+    match (actual, expected) {
+        (Some(actual), Some(expected)) => compare_ordered(actual, op, expected),
+        _ => false,
+    }
+}
+
+fn compare_ordered<T: PartialOrd>(actual: T, op: CompareOp, expected: T) -> bool {
+    match op {
+        CompareOp::Gt => actual > expected,
+        CompareOp::Gte => actual >= expected,
+        CompareOp::Lt => actual < expected,
+        CompareOp::Lte => actual <= expected,
+        _ => actual == expected,
+    }
+}
+
+fn effort_rank(effort: &str) -> Option<usize> {
+    EFFORT_SCALE.iter().position(|e| e.eq_ignore_ascii_case(effort.trim()))
+}
+
+fn sort_items(items: &mut [TaskQueryItem], sort_by: &str, descending: bool) -> Result<(), String> {
+    let status_rank = |status: &str| match status {
+        "in_progress" => 0,
+        "pending" => 1,
+        "completed" => 2,
+        _ => 3,
+    };
+    
+    match sort_by {
+        "spec" => items.sort_by(|a, b| a.spec_id.cmp(&b.spec_id).then_with(|| a.task.id.cmp(&b.task.id))),
+        "id" => items.sort_by(|a, b| a.task.id.cmp(&b.task.id)),
+        "name" => items.sort_by_key(|item| item.task.name.to_lowercase()),
+        "status" => items.sort_by_key(|item| status_rank(&item.task.status)),
+        "effort" => items.sort_by_key(|item| effort_rank(&item.task.effort).unwrap_or(EFFORT_SCALE.len())),
+        "created" => items.sort_by(|a, b| a.spec_created.cmp(&b.spec_created)),
+        "completed" => items.sort_by(|a, b| a.task.completed.cmp(&b.task.completed)),
+        "project" => items.sort_by(|a, b| a.project_name.cmp(&b.project_name)),
+        other => return Err(format!("Unknown sort field '{}'", other)),
+    }
+    
+    if descending {
+        items.reverse();
+    }
+    
+    Ok(())
+}
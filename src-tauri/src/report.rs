@@ -0,0 +1,547 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use pulldown_cmark::{html, Event, Options, Parser};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_spec_directory, scan_specs, spec_analysis, SpecMetadata, TaskInfo};
+use crate::dependencies::{project_specs, resolve_dependency, split_dependency};
+use crate::settings::SettingsStore;
+use crate::stale::{find_stale_tasks, stale_threshold};
+
+const NODE_WIDTH: usize = 200;
+const NODE_HEIGHT: usize = 48;
+const COLUMN_GAP: usize = 60;
+const ROW_GAP: usize = 20;
+const GRAPH_PADDING: usize = 20;
+
+const REPORT_STYLES: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, sans-serif; color: #1f2937; max-width: 1100px; margin: 0 auto; padding: 32px; line-height: 1.5; }
+h1 { border-bottom: 2px solid #e5e7eb; padding-bottom: 8px; }
+h2 { margin-top: 40px; border-bottom: 1px solid #e5e7eb; padding-bottom: 4px; }
+table { border-collapse: collapse; width: 100%; margin: 16px 0; font-size: 14px; }
+th, td { border: 1px solid #e5e7eb; padding: 6px 10px; text-align: left; vertical-align: top; }
+th { background: #f9fafb; }
+code, pre { background: #f3f4f6; border-radius: 4px; }
+pre { padding: 12px; overflow-x: auto; }
+.meta { color: #6b7280; font-size: 14px; }
+.graph { overflow-x: auto; border: 1px solid #e5e7eb; border-radius: 6px; padding: 8px; }
+"#;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportExport {
+    html_path: String,
+    markdown_path: String,
+}
+
+// One titled part of a report. The dependency graph is rendered differently per
+// format, so it is kept apart from the markdown sections.
+enum ReportSection {
+    Markdown { level: usize, title: String, body: String },
+    DependencyGraph { level: usize, tasks: Vec<TaskInfo> },
+}
+
+#[tauri::command]
+pub async fn export_spec_report(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    spec_path: String,
+    output_dir: String,
+) -> Result<ReportExport, String> {
+    let spec_dir = Path::new(&spec_path);
+    let spec = scan_spec_directory(spec_dir).await
+        .ok_or("Spec directory does not contain a readable tasks.json")?;
+    
+    let specs = match project_specs(spec_dir).await {
+        Ok(specs) => specs,
+        Err(e) => {
+            log::warn!("Failed to scan project specs for cross-spec dependencies: {}", e);
+            Vec::new()
+        }
+    };
+    
+    let title = format!("Spec Report: {}", spec.feature);
+    let sections = spec_sections(&spec, &specs, 2, stale_threshold(&app, &settings, None)).await;
+    
+    write_report(Path::new(&output_dir), &spec.id, &title, &spec_subtitle(&spec), &sections)
+}
+
+#[tauri::command]
+pub async fn export_project_report(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    project_path: String,
+    output_dir: String,
+) -> Result<ReportExport, String> {
+    let project_dir = Path::new(&project_path);
+    let project_name = project_dir.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("project")
+        .to_string();
+    
+    let specs = scan_specs(project_path.clone(), None).await?;
+    // Archived specs only matter as targets of cross-spec dependencies
+    let all_specs = scan_specs(project_path.clone(), Some(true)).await?;
+    if specs.is_empty() {
+        return Err("Project has no specs to export".to_string());
+    }
+    
+    let total_tasks: usize = specs.iter().map(|spec| spec.task_count).sum();
+    let completed_tasks: usize = specs.iter().map(|spec| spec.completed_tasks).sum();
+    
+    let mut overview = progress_markdown(completed_tasks, total_tasks);
+    overview.push_str("\n| Spec | Phase | Status | Progress |\n|---|---|---|---|\n");
+    for spec in &specs {
+        overview.push_str(&format!(
+            "| {} | {} | {} | {}/{} ({:.0}%) |\n",
+            table_cell(&spec.feature),
+            table_cell(&spec.phase),
+            table_cell(&spec.status),
+            spec.completed_tasks,
+            spec.task_count,
+            percent(spec.completed_tasks, spec.task_count)
+        ));
+    }
+    
+    let mut sections = vec![ReportSection::Markdown {
+        level: 2,
+        title: "Overview".to_string(),
+        body: overview,
+    }];
+    
+    let stale_after_days = stale_threshold(&app, &settings, None);
+    let stale = find_stale_tasks(project_dir, &specs, stale_after_days);
+    if !stale.is_empty() {
+        let mut body = format!(
+            "In-progress tasks started more than {} days ago or without activity since.\n\n| Spec | Task | In Progress Since | Last Activity | Reason |\n|---|---|---|---|---|\n",
+            stale_after_days
+        );
+        for task in &stale {
+            body.push_str(&format!(
+                "| {} | {}: {} | {} | {} | {} |\n",
+                table_cell(&task.spec_id),
+                table_cell(&task.task_id),
+                table_cell(&task.task_name),
+                task.in_progress_since.as_deref().unwrap_or(""),
+                task.last_activity.as_deref().unwrap_or(""),
+                table_cell(&task.reasons.join(", "))
+            ));
+        }
+        sections.push(ReportSection::Markdown {
+            level: 2,
+            title: "Stale Tasks".to_string(),
+            body,
+        });
+    }
+    
+    // Each spec gets its own heading with its sections nested one level below
+    for spec in &specs {
+        sections.push(ReportSection::Markdown {
+            level: 2,
+            title: spec.feature.clone(),
+            body: format!("_{}_\n", spec_subtitle(spec)),
+        });
+        sections.extend(spec_sections(spec, &all_specs, 3, stale_after_days).await);
+    }
+    
+    let title = format!("Project Report: {}", project_name);
+    let subtitle = format!("{} specs · generated {}", specs.len(), today());
+    
+    write_report(Path::new(&output_dir), &project_name, &title, &subtitle, &sections)
+}
+
+async fn spec_sections(
+    spec: &SpecMetadata,
+    project_specs: &[SpecMetadata],
+    heading_level: usize,
+    stale_after_days: u32,
+) -> Vec<ReportSection> {
+    let spec_dir = Path::new(&spec.path);
+    let mut sections = Vec::new();
+    
+    sections.push(ReportSection::Markdown {
+        level: heading_level,
+        title: "Progress".to_string(),
+        body: progress_markdown(spec.completed_tasks, spec.task_count),
+    });
+    sections.push(ReportSection::Markdown {
+        level: heading_level,
+        title: "Tasks".to_string(),
+        body: task_table_markdown(&spec.tasks),
+    });
+    sections.push(ReportSection::DependencyGraph {
+        level: heading_level,
+        tasks: graph_tasks(spec, project_specs),
+    });
+    
+    let tasks_file = spec_dir.join("tasks.json");
+    match spec_analysis(&tasks_file.to_string_lossy(), project_specs, stale_after_days).await {
+        Ok(analysis) => sections.push(ReportSection::Markdown {
+            level: heading_level,
+            title: "Analysis".to_string(),
+            // Drop the analysis' own title and nest its sections under ours
+            body: demote_headings(
+                &analysis.lines().skip_while(|line| line.starts_with("# ")).collect::<Vec<_>>().join("\n"),
+                heading_level - 1,
+            ),
+        }),
+        Err(e) => log::warn!("Failed to analyze spec {}: {}", spec.id, e),
+    }
+    
+    if let Ok(sdd) = fs::read_to_string(spec_dir.join("sdd.md")) {
+        sections.push(ReportSection::Markdown {
+            level: heading_level,
+            title: "Design Document".to_string(),
+            body: demote_headings(&sdd, heading_level),
+        });
+    }
+    
+    sections
+}
+
+fn write_report(
+    output_dir: &Path,
+    base_name: &str,
+    title: &str,
+    subtitle: &str,
+    sections: &[ReportSection],
+) -> Result<ReportExport, String> {
+    fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Failed to create output directory: {}", e))?;
+    
+    let html_path = report_path(output_dir, base_name, "html");
+    let markdown_path = report_path(output_dir, base_name, "md");
+    
+    fs::write(&html_path, render_html(title, subtitle, sections))
+        .map_err(|e| format!("Failed to write HTML report: {}", e))?;
+    fs::write(&markdown_path, render_markdown(title, subtitle, sections))
+        .map_err(|e| format!("Failed to write Markdown report: {}", e))?;
+    
+    Ok(ReportExport {
+        html_path: html_path.to_string_lossy().to_string(),
+        markdown_path: markdown_path.to_string_lossy().to_string(),
+    })
+}
+
+fn report_path(output_dir: &Path, base_name: &str, extension: &str) -> PathBuf {
+    let safe_name: String = base_name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    output_dir.join(format!("{}-report.{}", safe_name, extension))
+}
+
+fn render_markdown(title: &str, subtitle: &str, sections: &[ReportSection]) -> String {
+    let mut out = format!("# {}\n\n_{}_\n", title, subtitle);
+    
+    for section in sections {
+        match section {
+            ReportSection::Markdown { level, title, body } => {
+                out.push_str(&format!("\n{} {}\n\n{}\n", "#".repeat(*level), title, body.trim_end()));
+            }
+            ReportSection::DependencyGraph { level, tasks } => {
+                out.push_str(&format!(
+                    "\n{} Dependency Graph\n\n```mermaid\n{}```\n",
+                    "#".repeat(*level),
+                    mermaid_graph(tasks)
+                ));
+            }
+        }
+    }
+    
+    out
+}
+
+fn render_html(title: &str, subtitle: &str, sections: &[ReportSection]) -> String {
+    let mut body = format!(
+        "<h1>{}</h1>\n<p class=\"meta\">{}</p>\n",
+        escape_html(title),
+        escape_html(subtitle)
+    );
+    
+    for section in sections {
+        match section {
+            ReportSection::Markdown { level, title, body: markdown } => {
+                body.push_str(&markdown_to_html(&format!("{} {}\n\n{}", "#".repeat(*level), title, markdown)));
+            }
+            ReportSection::DependencyGraph { level, tasks } => {
+                body.push_str(&format!("<h{0}>Dependency Graph</h{0}>\n<div class=\"graph\">", level));
+                body.push_str(&svg_graph(tasks));
+                body.push_str("</div>\n");
+            }
+        }
+    }
+    
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        REPORT_STYLES,
+        body
+    )
+}
+
+fn markdown_to_html(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    
+    // Specs and task text are not trusted in a report that gets sent around,
+    // so raw HTML is rendered as text instead of being passed through
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        event => event,
+    });
+    
+    let mut out = String::new();
+    html::push_html(&mut out, events);
+    out
+}
+
+fn progress_markdown(completed: usize, total: usize) -> String {
+    let pct = percent(completed, total);
+    let filled = (pct / 5.0).round() as usize;
+    format!(
+        "**{}/{} tasks completed ({:.0}%)**\n\n`{}{}`\n",
+        completed,
+        total,
+        pct,
+        "█".repeat(filled),
+        "░".repeat(20 - filled.min(20))
+    )
+}
+
+fn task_table_markdown(tasks: &[TaskInfo]) -> String {
+    if tasks.is_empty() {
+        return "_No tasks defined._\n".to_string();
+    }
+    
+    let mut out = String::from("| ID | Name | Status | Effort | Dependencies | Completed |\n|---|---|---|---|---|---|\n");
+    for task in tasks {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            table_cell(&task.id),
+            table_cell(&task.name),
+            table_cell(&task.status),
+            table_cell(&task.effort),
+            table_cell(&task.dependencies.join(", ")),
+            table_cell(task.completed.as_deref().unwrap_or(""))
+        ));
+    }
+    out
+}
+
+// The spec's tasks followed by the tasks of other specs they depend on. Those
+// keep the qualified id the dependency was written with so the edges line up.
+fn graph_tasks(spec: &SpecMetadata, project_specs: &[SpecMetadata]) -> Vec<TaskInfo> {
+    let mut tasks = spec.tasks.clone();
+    for task in &spec.tasks {
+        for dep in &task.dependencies {
+            if split_dependency(dep).0.is_none() || tasks.iter().any(|known| &known.id == dep) {
+                continue;
+            }
+            if let Ok((dep_spec, dep_task)) = resolve_dependency(project_specs, spec, dep) {
+                tasks.push(TaskInfo {
+                    id: dep.clone(),
+                    name: format!("{}: {}", dep_spec.feature, dep_task.name),
+                    ..dep_task.clone()
+                });
+            }
+        }
+    }
+    tasks
+}
+
+// Tasks from other specs are the ones with a qualified id
+fn is_external(task: &TaskInfo) -> bool {
+    split_dependency(&task.id).0.is_some()
+}
+
+fn mermaid_graph(tasks: &[TaskInfo]) -> String {
+    let node_id = |id: &str| -> String {
+        let safe: String = id.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect();
+        format!("n_{}", safe)
+    };
+    
+    let mut out = String::from("graph LR\n");
+    out.push_str("  classDef completed fill:#d1fae5,stroke:#10b981\n");
+    out.push_str("  classDef in_progress fill:#fef3c7,stroke:#f59e0b\n");
+    out.push_str("  classDef pending fill:#f3f4f6,stroke:#9ca3af\n");
+    out.push_str("  classDef external stroke-dasharray:4 3\n");
+    
+    for task in tasks {
+        let label = format!("{}: {}", task.id, task.name).replace('"', "#quot;");
+        let class = match task.status.as_str() {
+            "completed" | "in_progress" => task.status.as_str(),
+            _ => "pending",
+        };
+        out.push_str(&format!("  {}[\"{}\"]:::{}\n", node_id(&task.id), label, class));
+        if is_external(task) {
+            out.push_str(&format!("  class {} external\n", node_id(&task.id)));
+        }
+    }
+    
+    for task in tasks {
+        for dep in &task.dependencies {
+            out.push_str(&format!("  {} --> {}\n", node_id(dep), node_id(&task.id)));
+        }
+    }
+    
+    out
+}
+
+// Lays tasks out left to right, one column per dependency depth
+fn svg_graph(tasks: &[TaskInfo]) -> String {
+    if tasks.is_empty() {
+        return "<p><em>No tasks defined.</em></p>".to_string();
+    }
+    
+    let index: HashMap<&str, usize> = tasks.iter()
+        .enumerate()
+        .map(|(i, task)| (task.id.as_str(), i))
+        .collect();
+    
+    let mut depths: Vec<Option<usize>> = vec![None; tasks.len()];
+    for i in 0..tasks.len() {
+        task_depth(i, tasks, &index, &mut depths, &mut Vec::new());
+    }
+    
+    let mut positions = vec![(0usize, 0usize); tasks.len()];
+    let mut rows_per_column: HashMap<usize, usize> = HashMap::new();
+    for (i, depth) in depths.iter().enumerate() {
+        let column = depth.unwrap_or(0);
+        let row = rows_per_column.entry(column).or_insert(0);
+        positions[i] = (
+            GRAPH_PADDING + column * (NODE_WIDTH + COLUMN_GAP),
+            GRAPH_PADDING + *row * (NODE_HEIGHT + ROW_GAP),
+        );
+        *row += 1;
+    }
+    
+    let columns = rows_per_column.keys().max().map_or(1, |max| max + 1);
+    let rows = rows_per_column.values().max().copied().unwrap_or(1);
+    let width = GRAPH_PADDING * 2 + columns * NODE_WIDTH + (columns - 1) * COLUMN_GAP;
+    let height = GRAPH_PADDING * 2 + rows * NODE_HEIGHT + (rows - 1) * ROW_GAP;
+    
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"12\">\n\
+         <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto\"><path d=\"M0,0 L10,5 L0,10 z\" fill=\"#9ca3af\"/></marker></defs>\n",
+        w = width,
+        h = height
+    );
+    
+    for (i, task) in tasks.iter().enumerate() {
+        for dep in &task.dependencies {
+            if let Some(&from) = index.get(dep.as_str()) {
+                let (x1, y1) = positions[from];
+                let (x2, y2) = positions[i];
+                svg.push_str(&format!(
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#9ca3af\" stroke-width=\"1.5\" marker-end=\"url(#arrow)\"/>\n",
+                    x1 + NODE_WIDTH,
+                    y1 + NODE_HEIGHT / 2,
+                    x2,
+                    y2 + NODE_HEIGHT / 2
+                ));
+            }
+        }
+    }
+    
+    for (i, task) in tasks.iter().enumerate() {
+        let (x, y) = positions[i];
+        let (fill, stroke) = match task.status.as_str() {
+            "completed" => ("#d1fae5", "#10b981"),
+            "in_progress" => ("#fef3c7", "#f59e0b"),
+            _ => ("#f3f4f6", "#9ca3af"),
+        };
+        let name: String = if task.name.chars().count() > 28 {
+            format!("{}…", task.name.chars().take(27).collect::<String>())
+        } else {
+            task.name.clone()
+        };
+        
+        svg.push_str(&format!(
+            "<g><title>{}</title><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"6\" fill=\"{}\" stroke=\"{}\"{}/>\
+             <text x=\"{}\" y=\"{}\" font-weight=\"bold\">{}</text><text x=\"{}\" y=\"{}\">{}</text></g>\n",
+            escape_html(&format!("{} ({})", task.name, task.status)),
+            x, y, NODE_WIDTH, NODE_HEIGHT, fill, stroke,
+            if is_external(task) { " stroke-dasharray=\"4 3\"" } else { "" },
+            x + 10, y + 20, escape_html(&task.id),
+            x + 10, y + 38, escape_html(&name)
+        ));
+    }
+    
+    svg.push_str("</svg>");
+    svg
+}
+
+// Depth of a task in the dependency graph; cycles and unknown dependencies
+// are treated as roots so every task still gets a column
+fn task_depth(
+    i: usize,
+    tasks: &[TaskInfo],
+    index: &HashMap<&str, usize>,
+    depths: &mut Vec<Option<usize>>,
+    visiting: &mut Vec<usize>,
+) -> usize {
+    if let Some(depth) = depths[i] {
+        return depth;
+    }
+    if visiting.contains(&i) {
+        return 0;
+    }
+    
+    visiting.push(i);
+    let depth = tasks[i].dependencies.iter()
+        .filter_map(|dep| index.get(dep.as_str()))
+        .map(|&dep| task_depth(dep, tasks, index, depths, visiting) + 1)
+        .max()
+        .unwrap_or(0);
+    visiting.pop();
+    
+    depths[i] = Some(depth);
+    depth
+}
+
+// Pushes every markdown heading down by `levels`, leaving fenced code alone
+fn demote_headings(markdown: &str, levels: usize) -> String {
+    let mut in_fence = false;
+    let mut out = String::new();
+    
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && line.starts_with('#') {
+            out.push_str(&"#".repeat(levels));
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    
+    out
+}
+
+fn spec_subtitle(spec: &SpecMetadata) -> String {
+    format!(
+        "{} · {} · status: {} · created {} · generated {}",
+        spec.id, spec.phase, spec.status, spec.created, today()
+    )
+}
+
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn percent(completed: usize, total: usize) -> f32 {
+    if total > 0 { (completed as f32 / total as f32) * 100.0 } else { 0.0 }
+}
+
+fn escape_html(value: &str) -> String {
+    value.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn today() -> String {
+    chrono::Utc::now().format("%Y-%m-%d").to_string()
+}
@@ -0,0 +1,491 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{scan_specs, write_file_atomic, SpecMetadata};
+use crate::tasks::load_tasks_file;
+
+// tasks.json field a spec can use to name its roadmap item explicitly
+const SPEC_ROADMAP_FIELD: &str = "roadmap_item";
+// Shorter names than this are too likely to match unrelated items
+const MIN_MATCH_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Roadmap {
+    pub(crate) title: String,
+    pub(crate) phases: Vec<RoadmapPhase>,
+    pub(crate) progress_log: Vec<ProgressLogEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoadmapPhase {
+    // Full heading text, e.g. "Phase 1: Foundation"
+    pub(crate) name: String,
+    pub(crate) line: usize,
+    pub(crate) items: Vec<RoadmapItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoadmapItem {
+    pub(crate) text: String,
+    pub(crate) checked: bool,
+    // 1-based line in roadmap.md
+    pub(crate) line: usize,
+    pub(crate) phase: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProgressLogEntry {
+    pub(crate) date: String,
+    pub(crate) title: String,
+    pub(crate) what: String,
+    pub(crate) why: String,
+    pub(crate) impact: String,
+    #[serde(default)]
+    pub(crate) line: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoadmapReport {
+    roadmap: Roadmap,
+    links: Vec<SpecRoadmapLink>,
+    // Roadmap items no spec is linked to
+    unlinked_items: Vec<RoadmapItem>,
+    // Specs linked to neither an item nor a phase
+    unlinked_specs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecRoadmapLink {
+    pub(crate) spec_id: String,
+    pub(crate) feature: String,
+    pub(crate) items: Vec<RoadmapItem>,
+    // Phase matched through the spec's `phase` field, when no item matched
+    pub(crate) phase: Option<String>,
+}
+
+#[tauri::command]
+pub async fn get_roadmap(project_path: String) -> Result<RoadmapReport, String> {
+    let project_dir = Path::new(&project_path);
+    let roadmap = load_roadmap(project_dir)?
+        .ok_or("No roadmap.md found in .agent-sdd/product")?;
+    let specs = scan_specs(project_path.clone(), Some(true)).await?;
+    
+    let links: Vec<SpecRoadmapLink> = specs.iter().map(|spec| link_spec(&roadmap, spec)).collect();
+    
+    let unlinked_items = roadmap.phases.iter()
+        .flat_map(|phase| phase.items.iter())
+        .filter(|item| !links.iter().any(|link| link.items.iter().any(|linked| linked.line == item.line)))
+        .cloned()
+        .collect();
+    let unlinked_specs = links.iter()
+        .filter(|link| link.items.is_empty() && link.phase.is_none())
+        .map(|link| link.spec_id.clone())
+        .collect();
+    
+    Ok(RoadmapReport {
+        roadmap,
+        links,
+        unlinked_items,
+        unlinked_specs,
+    })
+}
+
+// Ticks the roadmap checkboxes linked to a spec once all of its tasks are
+// completed. Returns the text of the items that were ticked.
+#[tauri::command]
+pub async fn complete_roadmap_items(spec_path: String) -> Result<Vec<String>, String> {
+    let spec_dir = Path::new(&spec_path);
+    let project_dir = project_root(spec_dir)?;
+    let spec = crate::commands::scan_spec_directory(spec_dir)
+        .await
+        .ok_or("Could not read spec tasks.json")?;
+    
+    if spec.task_count == 0 || spec.completed_tasks < spec.task_count {
+        return Err(format!(
+            "Spec '{}' is not complete ({}/{} tasks done)",
+            spec.id, spec.completed_tasks, spec.task_count
+        ));
+    }
+    
+    tick_spec_items(project_dir, &spec)
+}
+
+// Appends an entry to the roadmap's Progress Log. The frontend passes back the
+// entry drafted by `update_task_status` once the user has confirmed (and
+// possibly edited) it; without one a fresh draft is used.
+#[tauri::command]
+pub async fn append_progress_log(spec_path: String, entry: Option<ProgressLogEntry>) -> Result<ProgressLogEntry, String> {
+    let spec_dir = Path::new(&spec_path);
+    let project_dir = project_root(spec_dir)?;
+    let entry = match entry {
+        Some(entry) => entry,
+        None => draft_progress_log_entry(spec_dir).await?,
+    };
+    
+    let path = roadmap_path(project_dir);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read roadmap.md: {}", e))?;
+    let roadmap = parse_roadmap(&content);
+    if roadmap.progress_log.iter().any(|logged| logged.title == entry.title && logged.date == entry.date) {
+        return Err(format!("Progress Log already has an entry for '{}' on {}", entry.title, entry.date));
+    }
+    
+    write_file_atomic(&path, &insert_progress_log_entry(&content, &entry))?;
+    Ok(entry)
+}
+
+// Builds a Progress Log entry for a completed spec from its tasks.json, the
+// goal in sdd.md, any task notes and the roadmap items it is linked to
+pub(crate) async fn draft_progress_log_entry(spec_dir: &Path) -> Result<ProgressLogEntry, String> {
+    let project_dir = project_root(spec_dir)?;
+    let spec = crate::commands::scan_spec_directory(spec_dir)
+        .await
+        .ok_or("Could not read spec tasks.json")?;
+    let tasks_file = load_tasks_file(spec_dir)?;
+    
+    let task_names: Vec<&str> = spec.tasks.iter().map(|task| task.name.as_str()).collect();
+    let what = format!(
+        "Completed {} ({} {}: {}).",
+        spec.feature,
+        task_names.len(),
+        if task_names.len() == 1 { "task" } else { "tasks" },
+        task_names.join(", ")
+    );
+    
+    let why = fs::read_to_string(spec_dir.join("sdd.md"))
+        .ok()
+        .and_then(|sdd| sdd.lines().find_map(|line| line.trim().strip_prefix("**Goal:**").map(|goal| goal.trim().to_string())))
+        .filter(|goal| !goal.is_empty())
+        .unwrap_or_else(|| format!("Delivers the work planned in spec {}.", spec.id));
+    
+    let notes: Vec<String> = tasks_file.tasks.iter()
+        .filter_map(|task| task.extra.get("notes").and_then(|notes| notes.as_str()))
+        .map(|notes| notes.trim().trim_end_matches('.').to_string())
+        .filter(|notes| !notes.is_empty())
+        .collect();
+    let linked_items: Vec<String> = load_roadmap(project_dir)?
+        .map(|roadmap| link_spec(&roadmap, &spec).items.into_iter().map(|item| item.text).collect())
+        .unwrap_or_default();
+    let impact = if !notes.is_empty() {
+        format!("{}.", notes.join("; "))
+    } else if !linked_items.is_empty() {
+        format!("Roadmap items done: {}.", linked_items.join(", "))
+    } else {
+        format!("All tasks in {} are complete.", spec.id)
+    };
+    
+    Ok(ProgressLogEntry {
+        date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+        title: format!("Completed {}", spec.feature),
+        what,
+        why,
+        impact,
+        line: 0,
+    })
+}
+
+pub(crate) fn format_progress_log_entry(entry: &ProgressLogEntry) -> String {
+    format!(
+        "**[{}] – {}**\n- **What:** {}\n- **Why:** {}\n- **Impact:** {}\n",
+        entry.date, entry.title, entry.what, entry.why, entry.impact
+    )
+}
+
+// Adds the entry at the end of the Progress Log section, creating the section
+// at the end of the file if the roadmap doesn't have one
+fn insert_progress_log_entry(content: &str, entry: &ProgressLogEntry) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let log_start = lines.iter().position(|line| {
+        heading_text(line.trim()).is_some_and(|heading| heading.to_lowercase().contains("progress log"))
+    });
+    
+    let (before, after): (Vec<&str>, Vec<&str>) = match log_start {
+        Some(start) => {
+            let end = lines[start + 1..].iter()
+                .position(|line| heading_text(line.trim()).is_some())
+                .map_or(lines.len(), |offset| start + 1 + offset);
+            (lines[..end].to_vec(), lines[end..].to_vec())
+        }
+        None => {
+            let mut before = lines.clone();
+            before.extend(["", "## Progress Log"]);
+            (before, Vec::new())
+        }
+    };
+    
+    let mut out: Vec<String> = before.iter().map(|line| line.to_string()).collect();
+    while out.last().is_some_and(|line| line.trim().is_empty()) {
+        out.pop();
+    }
+    out.push(String::new());
+    out.extend(format_progress_log_entry(entry).lines().map(|line| line.to_string()));
+    if !after.is_empty() {
+        out.push(String::new());
+        out.extend(after.iter().map(|line| line.to_string()));
+    }
+    
+    let mut result = out.join("\n");
+    result.push('\n');
+    result
+}
+
+pub(crate) fn roadmap_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".agent-sdd").join("product").join("roadmap.md")
+}
+
+pub(crate) fn project_root(spec_dir: &Path) -> Result<&Path, String> {
+    spec_dir.ancestors()
+        .find(|p| p.join(".agent-sdd").is_dir())
+        .ok_or_else(|| "Could not find project root with .agent-sdd".to_string())
+}
+
+pub(crate) fn load_roadmap(project_dir: &Path) -> Result<Option<Roadmap>, String> {
+    let path = roadmap_path(project_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read roadmap.md: {}", e))?;
+    Ok(Some(parse_roadmap(&content)))
+}
+
+// Understands both layouts in use: phases as `## Phase 1: ...` headings, and
+// phases as top-level `- Phase 1: ...` bullets with nested checkboxes. Any
+// heading mentioning "Progress Log" starts the log section.
+pub(crate) fn parse_roadmap(content: &str) -> Roadmap {
+    let mut roadmap = Roadmap::default();
+    let mut in_log = false;
+    
+    for (index, raw) in content.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = raw.trim();
+        
+        if let Some(heading) = heading_text(trimmed) {
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            if level == 1 && roadmap.title.is_empty() {
+                roadmap.title = heading.to_string();
+                continue;
+            }
+            in_log = heading.to_lowercase().contains("progress log");
+            if !in_log {
+                roadmap.phases.push(RoadmapPhase { name: heading.to_string(), line, items: Vec::new() });
+            }
+            continue;
+        }
+        
+        if in_log {
+            parse_log_line(trimmed, line, &mut roadmap.progress_log);
+            continue;
+        }
+        
+        if let Some((checked, text)) = checkbox(trimmed) {
+            if roadmap.phases.is_empty() {
+                roadmap.phases.push(RoadmapPhase { name: String::new(), line, items: Vec::new() });
+            }
+            if let Some(phase) = roadmap.phases.last_mut() {
+                phase.items.push(RoadmapItem {
+                    text: text.to_string(),
+                    checked,
+                    line,
+                    phase: phase.name.clone(),
+                });
+            }
+        } else if let Some(text) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            if text.to_lowercase().starts_with("phase") {
+                roadmap.phases.push(RoadmapPhase { name: text.trim().to_string(), line, items: Vec::new() });
+            }
+        }
+    }
+    
+    roadmap
+}
+
+fn heading_text(line: &str) -> Option<&str> {
+    let text = line.trim_start_matches('#');
+    if text.len() < line.len() && (text.is_empty() || text.starts_with(' ')) {
+        Some(text.trim())
+    } else {
+        None
+    }
+}
+
+fn checkbox(line: &str) -> Option<(bool, &str)> {
+    let rest = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))?;
+    if let Some(text) = rest.strip_prefix("[ ]") {
+        Some((false, text.trim()))
+    } else if let Some(text) = rest.strip_prefix("[x]").or_else(|| rest.strip_prefix("[X]")) {
+        Some((true, text.trim()))
+    } else {
+        None
+    }
+}
+
+// Entries look like `**[2025-08-14] – Title**` followed by What/Why/Impact bullets
+fn parse_log_line(line: &str, line_number: usize, log: &mut Vec<ProgressLogEntry>) {
+    if let Some(inner) = line.strip_prefix("**[").and_then(|rest| rest.strip_suffix("**")) {
+        if let Some((date, title)) = inner.split_once(']') {
+            let title = title.trim().trim_start_matches(['–', '—', '-']).trim();
+            log.push(ProgressLogEntry {
+                date: date.trim().to_string(),
+                title: title.to_string(),
+                line: line_number,
+                ..ProgressLogEntry::default()
+            });
+        }
+        return;
+    }
+    
+    let entry = match log.last_mut() {
+        Some(entry) => entry,
+        None => return,
+    };
+    let bullet = line.trim_start_matches(['-', '*']).trim();
+    for (label, field) in [
+        ("**What:**", &mut entry.what),
+        ("**Why:**", &mut entry.why),
+        ("**Impact:**", &mut entry.impact),
+    ] {
+        if let Some(value) = bullet.strip_prefix(label) {
+            *field = value.trim().to_string();
+        }
+    }
+}
+
+// Links a spec to the roadmap items that name it: an item mentioning the spec
+// id, the item named by the spec's `roadmap_item` field, or an item containing
+// the spec's feature name. Falls back to the phase named in tasks.json.
+pub(crate) fn link_spec(roadmap: &Roadmap, spec: &SpecMetadata) -> SpecRoadmapLink {
+    let explicit = load_tasks_file(Path::new(&spec.path))
+        .ok()
+        .and_then(|tasks| tasks.extra.get(SPEC_ROADMAP_FIELD).and_then(|v| v.as_str()).map(normalize));
+    let feature = normalize(&spec.feature);
+    let slug = normalize(spec_slug(&spec.id));
+    let spec_id = spec.id.to_lowercase();
+    
+    let items: Vec<RoadmapItem> = roadmap.phases.iter()
+        .flat_map(|phase| phase.items.iter())
+        .filter(|item| {
+            let text = normalize(&item.text);
+            item.text.to_lowercase().contains(&spec_id)
+                || explicit.as_ref().is_some_and(|explicit| !explicit.is_empty() && contains_words(&text, explicit))
+                || (feature.len() >= MIN_MATCH_LENGTH && contains_words(&text, &feature))
+                || (slug.len() >= MIN_MATCH_LENGTH && contains_words(&text, &slug))
+        })
+        .cloned()
+        .collect();
+    
+    let phase = if items.is_empty() {
+        find_phase(roadmap, &spec.phase).map(|phase| phase.name.clone())
+    } else {
+        None
+    };
+    
+    SpecRoadmapLink {
+        spec_id: spec.id.clone(),
+        feature: spec.feature.clone(),
+        items,
+        phase,
+    }
+}
+
+// Matches "Phase 1" against "Phase 1: Foundation", or a phase title on its own
+fn find_phase<'a>(roadmap: &'a Roadmap, phase: &str) -> Option<&'a RoadmapPhase> {
+    let wanted = normalize(phase);
+    if wanted.is_empty() {
+        return None;
+    }
+    
+    roadmap.phases.iter().find(|candidate| {
+        let (key, title) = candidate.name.split_once(':').unwrap_or((&candidate.name, ""));
+        normalize(&candidate.name) == wanted || normalize(key) == wanted || normalize(title) == wanted
+    })
+}
+
+pub(crate) fn tick_spec_items(project_dir: &Path, spec: &SpecMetadata) -> Result<Vec<String>, String> {
+    let roadmap = load_roadmap(project_dir)?.ok_or("No roadmap.md found in .agent-sdd/product")?;
+    let link = link_spec(&roadmap, spec);
+    let lines: Vec<usize> = link.items.iter().filter(|item| !item.checked).map(|item| item.line).collect();
+    if lines.is_empty() {
+        return Ok(Vec::new());
+    }
+    
+    let path = roadmap_path(project_dir);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read roadmap.md: {}", e))?;
+    let updated: Vec<String> = content.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if lines.contains(&(index + 1)) {
+                line.replacen("[ ]", "[x]", 1)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    
+    let mut updated = updated.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    write_file_atomic(&path, &updated)?;
+    
+    Ok(link.items.into_iter().filter(|item| !item.checked).map(|item| item.text).collect())
+}
+
+// Spec folders are named `YYYY-MM-DD-name` or `name-YYYY-MM-DD`; the name part
+// is what roadmap items and decisions mention
+pub(crate) fn spec_slug(spec_id: &str) -> &str {
+    let is_date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok();
+    if spec_id.len() <= 11 {
+        return spec_id;
+    }
+    
+    // Names can be non-ASCII, so only take slices that land on char boundaries
+    if let (Some(date), Some(rest)) = (spec_id.get(..10), spec_id.get(10..)) {
+        if is_date(date) && rest.starts_with('-') {
+            return &rest[1..];
+        }
+    }
+    let split = spec_id.len() - 11;
+    if let (Some(name), Some(rest)) = (spec_id.get(..split), spec_id.get(split..)) {
+        if rest.starts_with('-') && is_date(&rest[1..]) {
+            return name;
+        }
+    }
+    spec_id
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Whole-word containment on normalized text, so "auth" doesn't match "oauth"
+fn contains_words(text: &str, words: &str) -> bool {
+    format!(" {} ", text).contains(&format!(" {} ", words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spec_slug;
+    
+    #[test]
+    fn spec_slug_strips_leading_or_trailing_date() {
+        assert_eq!(spec_slug("2024-01-15-user-auth"), "user-auth");
+        assert_eq!(spec_slug("user-auth-2024-01-15"), "user-auth");
+        assert_eq!(spec_slug("user-auth"), "user-auth");
+    }
+    
+    #[test]
+    fn spec_slug_handles_non_ascii_names() {
+        assert_eq!(spec_slug("über-große-suche"), "über-große-suche");
+        assert_eq!(spec_slug("große-suche-äöü"), "große-suche-äöü");
+        assert_eq!(spec_slug("2024-01-15-über-große-suche"), "über-große-suche");
+        assert_eq!(spec_slug("über-große-suche-2024-01-15"), "über-große-suche");
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::scan_directory_recursive;
+
+const INDEXED_SECTIONS: [&str; 5] = ["standards", "product", "specs", "instructions", "agents"];
+const INDEXED_EXTENSIONS: [&str; 2] = ["md", "json"];
+const MAX_INDEXED_FILE_SIZE: u64 = 10 * 1024 * 1024;
+const INDEX_FORMAT_VERSION: u32 = 1;
+const DEFAULT_RESULT_LIMIT: usize = 50;
+const MAX_SNIPPETS_PER_HIT: usize = 3;
+const MAX_SNIPPET_CHARS: usize = 160;
+
+// Search indexes kept in memory for the lifetime of the app, keyed by project path
+#[derive(Default)]
+pub struct SearchIndexCache(Mutex<HashMap<String, SearchIndex>>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    files: HashMap<String, IndexedFile>,
+    // term -> file key -> positions of the term in that file
+    postings: BTreeMap<String, HashMap<String, Vec<TermPosition>>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedFile {
+    section: String,
+    rel_path: String,
+    full_path: String,
+    size: u64,
+    mtime: Option<u64>,
+    lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct TermPosition {
+    // Ordinal of the token within the file, used for phrase matching
+    token: u32,
+    // Zero-based line the token appears on
+    line: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchResults {
+    query: String,
+    total_hits: usize,
+    hits: Vec<SearchHit>,
+    indexed_files: usize,
+    reindexed_files: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchHit {
+    section: String,
+    rel_path: String,
+    full_path: String,
+    score: f64,
+    match_count: usize,
+    matches: Vec<SearchMatch>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchMatch {
+    line: usize,
+    snippet: String,
+}
+
+// A single query clause: a bare word, a quoted phrase or a `prefix*`.
+// Every token must appear in order; the last one may be a prefix.
+#[derive(Debug)]
+struct QueryClause {
+    tokens: Vec<String>,
+    prefix_last: bool,
+}
+
+#[tauri::command]
+pub async fn search_project(
+    cache: tauri::State<'_, SearchIndexCache>,
+    project_path: String,
+    query: String,
+    limit: Option<usize>,
+    persist: Option<bool>,
+) -> Result<SearchResults, String> {
+    let agent_sdd_dir = Path::new(&project_path).join(".agent-sdd");
+    if !agent_sdd_dir.exists() || !agent_sdd_dir.is_dir() {
+        return Err("Project does not contain .agent-sdd directory".to_string());
+    }
+    
+    let clauses = parse_query(&query);
+    if clauses.is_empty() {
+        return Err("Search query is empty".to_string());
+    }
+    
+    let persist = persist.unwrap_or(false);
+    let mut indexes = cache.0.lock()
+        .map_err(|e| format!("Failed to lock search index: {}", e))?;
+    
+    let index = indexes.entry(project_path.clone()).or_insert_with(|| {
+        if persist {
+            SearchIndex::load(&agent_sdd_dir)
+        } else {
+            SearchIndex::default()
+        }
+    });
+    
+    let reindexed_files = index.refresh(&agent_sdd_dir);
+    if persist && (reindexed_files > 0 || !index_cache_path(&agent_sdd_dir).exists()) {
+        if let Err(e) = index.save(&agent_sdd_dir) {
+            log::warn!("Failed to persist search index for {}: {}", project_path, e);
+        }
+    }
+    
+    let mut hits = index.search(&clauses);
+    let total_hits = hits.len();
+    hits.truncate(limit.unwrap_or(DEFAULT_RESULT_LIMIT));
+    
+    Ok(SearchResults {
+        query,
+        total_hits,
+        hits,
+        indexed_files: index.files.len(),
+        reindexed_files,
+    })
+}
+
+impl SearchIndex {
+    fn load(agent_sdd_dir: &Path) -> SearchIndex {
+        let cache_path = index_cache_path(agent_sdd_dir);
+        let index = fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<SearchIndex>(&content).ok());
+        
+        match index {
+            Some(index) if index.version == INDEX_FORMAT_VERSION => index,
+            Some(_) => {
+                log::info!("Discarding outdated search index at {}", cache_path.display());
+                SearchIndex::default()
+            }
+            None => SearchIndex::default(),
+        }
+    }
+    
+    fn save(&self, agent_sdd_dir: &Path) -> Result<(), String> {
+        let cache_path = index_cache_path(agent_sdd_dir);
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+        
+        let content = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize search index: {}", e))?;
+        fs::write(&cache_path, content)
+            .map_err(|e| format!("Failed to write search index: {}", e))
+    }
+    
+    // Brings the index in line with the files on disk, re-indexing only files whose
+    // size or mtime changed. Returns the number of files added, updated or removed.
+    fn refresh(&mut self, agent_sdd_dir: &Path) -> usize {
+        self.version = INDEX_FORMAT_VERSION;
+        
+        let mut seen = HashSet::new();
+        let mut changed = 0;
+        
+        for section_name in INDEXED_SECTIONS {
+            let section_path = agent_sdd_dir.join(section_name);
+            if !section_path.is_dir() {
+                continue;
+            }
+            
+            let files = match scan_directory_recursive(&section_path, &section_path) {
+                Ok(files) => files,
+                Err(e) => {
+                    log::warn!("Failed to scan section {} for search: {}", section_name, e);
+                    continue;
+                }
+            };
+            
+            for file_info in files {
+                if !is_indexable(&file_info.rel_path, file_info.size) {
+                    continue;
+                }
+                
+                let key = format!("{}/{}", section_name, file_info.rel_path);
+                seen.insert(key.clone());
+                
+                let unchanged = self.files.get(&key)
+                    .map(|f| f.size == file_info.size && f.mtime == file_info.mtime)
+                    .unwrap_or(false);
+                if unchanged {
+                    continue;
+                }
+                
+                let content = match fs::read_to_string(&file_info.full_path) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        log::warn!("Failed to read {} for search: {}", file_info.full_path, e);
+                        continue;
+                    }
+                };
+                
+                self.remove_file(&key);
+                self.add_file(key, IndexedFile {
+                    section: section_name.to_string(),
+                    rel_path: file_info.rel_path,
+                    full_path: file_info.full_path,
+                    size: file_info.size,
+                    mtime: file_info.mtime,
+                    lines: content.lines().map(|line| line.to_string()).collect(),
+                });
+                changed += 1;
+            }
+        }
+        
+        let removed: Vec<String> = self.files.keys()
+            .filter(|key| !seen.contains(*key))
+            .cloned()
+            .collect();
+        for key in removed {
+            self.remove_file(&key);
+            changed += 1;
+        }
+        
+        changed
+    }
+    
+    fn add_file(&mut self, key: String, file: IndexedFile) {
+        let mut token = 0u32;
+        for (line_no, line) in file.lines.iter().enumerate() {
+            for term in tokenize(line) {
+                self.postings.entry(term)
+                    .or_default()
+                    .entry(key.clone())
+                    .or_default()
+                    .push(TermPosition { token, line: line_no as u32 });
+                token += 1;
+            }
+        }
+        self.files.insert(key, file);
+    }
+    
+    fn remove_file(&mut self, key: &str) {
+        let file = match self.files.remove(key) {
+            Some(file) => file,
+            None => return,
+        };
+        
+        let terms: HashSet<String> = file.lines.iter().flat_map(|line| tokenize(line)).collect();
+        for term in terms {
+            if let Some(files) = self.postings.get_mut(&term) {
+                files.remove(key);
+                if files.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+    }
+    
+    fn search(&self, clauses: &[QueryClause]) -> Vec<SearchHit> {
+        let total_files = self.files.len().max(1) as f64;
+        let mut scores: HashMap<&str, f64> = HashMap::new();
+        let mut match_lines: HashMap<&str, Vec<u32>> = HashMap::new();
+        
+        for (i, clause) in clauses.iter().enumerate() {
+            let matches = self.match_clause(clause);
+            
+            // Every clause must match, so drop files missing from this one
+            if i > 0 {
+                scores.retain(|key, _| matches.contains_key(key));
+                match_lines.retain(|key, _| matches.contains_key(key));
+            }
+            
+            let idf = (1.0 + total_files / matches.len().max(1) as f64).ln();
+            for (key, lines) in matches {
+                if i > 0 && !scores.contains_key(key) {
+                    continue;
+                }
+                *scores.entry(key).or_insert(0.0) += (1.0 + (lines.len() as f64).ln()) * idf;
+                match_lines.entry(key).or_default().extend(lines);
+            }
+        }
+        
+        let mut hits: Vec<SearchHit> = scores.into_iter()
+            .filter_map(|(key, score)| {
+                let file = self.files.get(key)?;
+                let mut lines = match_lines.remove(key).unwrap_or_default();
+                let match_count = lines.len();
+                lines.sort_unstable();
+                lines.dedup();
+                
+                let matches = lines.into_iter()
+                    .take(MAX_SNIPPETS_PER_HIT)
+                    .map(|line| SearchMatch {
+                        line: line as usize + 1,
+                        snippet: make_snippet(&file.lines[line as usize]),
+                    })
+                    .collect();
+                
+                Some(SearchHit {
+                    section: file.section.clone(),
+                    rel_path: file.rel_path.clone(),
+                    full_path: file.full_path.clone(),
+                    score,
+                    match_count,
+                    matches,
+                })
+            })
+            .collect();
+        
+        hits.sort_by(|a, b| {
+            b.score.partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.section.cmp(&b.section))
+                .then_with(|| a.rel_path.cmp(&b.rel_path))
+        });
+        
+        hits
+    }
+    
+    // Returns, per file, the line of every occurrence of the clause
+    fn match_clause(&self, clause: &QueryClause) -> HashMap<&str, Vec<u32>> {
+        // For each token in the clause: file -> token ordinal -> line
+        let mut per_token: Vec<HashMap<&str, HashMap<u32, u32>>> = Vec::new();
+        
+        for (i, token) in clause.tokens.iter().enumerate() {
+            let is_prefix = clause.prefix_last && i == clause.tokens.len() - 1;
+            let mut positions: HashMap<&str, HashMap<u32, u32>> = HashMap::new();
+            
+            let terms: Vec<&HashMap<String, Vec<TermPosition>>> = if is_prefix {
+                self.postings.range(token.clone()..)
+                    .take_while(|(term, _)| term.starts_with(token.as_str()))
+                    .map(|(_, files)| files)
+                    .collect()
+            } else {
+                self.postings.get(token).into_iter().collect()
+            };
+            
+            for files in terms {
+                for (key, term_positions) in files {
+                    let entry = positions.entry(key.as_str()).or_default();
+                    for position in term_positions {
+                        entry.insert(position.token, position.line);
+                    }
+                }
+            }
+            
+            if positions.is_empty() {
+                return HashMap::new();
+            }
+            per_token.push(positions);
+        }
+        
+        let mut matches: HashMap<&str, Vec<u32>> = HashMap::new();
+        let (first, rest) = match per_token.split_first() {
+            Some(split) => split,
+            None => return matches,
+        };
+        
+        for (key, starts) in first {
+            let mut lines: Vec<u32> = starts.iter()
+                .filter(|(start, _)| {
+                    rest.iter().enumerate().all(|(offset, positions)| {
+                        positions.get(key)
+                            .map(|p| p.contains_key(&(**start + offset as u32 + 1)))
+                            .unwrap_or(false)
+                    })
+                })
+                .map(|(_, line)| *line)
+                .collect();
+            
+            if !lines.is_empty() {
+                lines.sort_unstable();
+                matches.insert(key, lines);
+            }
+        }
+        
+        matches
+    }
+}
+
+fn index_cache_path(agent_sdd_dir: &Path) -> std::path::PathBuf {
+    agent_sdd_dir.join(".cache").join("search-index.json")
+}
+
+fn is_indexable(rel_path: &str, size: u64) -> bool {
+    if size > MAX_INDEXED_FILE_SIZE {
+        return false;
+    }
+    
+    Path::new(rel_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| INDEXED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+// Splits a query into clauses. Quoted text is a phrase, a trailing `*` makes the
+// last token a prefix, and a bare word that tokenizes to several tokens (such as
+// `TASK-001`) is matched as a phrase.
+fn parse_query(query: &str) -> Vec<QueryClause> {
+    let mut clauses = Vec::new();
+    let mut rest = query.trim();
+    
+    while !rest.is_empty() {
+        let (raw, remainder) = if let Some(stripped) = rest.strip_prefix('"') {
+            match stripped.find('"') {
+                Some(end) => (&stripped[..end], &stripped[end + 1..]),
+                None => (stripped, ""),
+            }
+        } else {
+            match rest.find(char::is_whitespace) {
+                Some(end) => (&rest[..end], &rest[end..]),
+                None => (rest, ""),
+            }
+        };
+        rest = remainder.trim_start();
+        
+        let raw = raw.trim();
+        let prefix_last = raw.ends_with('*');
+        let tokens = tokenize(raw.trim_end_matches('*'));
+        if !tokens.is_empty() {
+            clauses.push(QueryClause { tokens, prefix_last });
+        }
+    }
+    
+    clauses
+}
+
+fn make_snippet(line: &str) -> String {
+    let trimmed = line.trim();
+    if trimmed.chars().count() <= MAX_SNIPPET_CHARS {
+        return trimmed.to_string();
+    }
+    
+    let mut snippet: String = trimmed.chars().take(MAX_SNIPPET_CHARS).collect();
+    snippet.push('…');
+    snippet
+}
@@ -0,0 +1,250 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::Manager;
+
+use crate::commands::write_file_atomic;
+use crate::stale::DEFAULT_STALE_AFTER_DAYS;
+
+const SETTINGS_FILE: &str = "settings.json";
+const SETTINGS_SCHEMA_VERSION: u32 = 1;
+const MAX_RECENT_BASE_DIRS: usize = 10;
+
+// Each entry upgrades a settings document by one version: MIGRATIONS[0] turns
+// version 1 into version 2, and so on. Append a step when the schema changes.
+const MIGRATIONS: [fn(&mut Map<String, Value>); 0] = [];
+
+// Settings loaded from disk, kept in memory so concurrent updates don't race
+#[derive(Default)]
+pub struct SettingsStore(Mutex<Option<AppSettings>>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    schema_version: u32,
+    // Most recently used first
+    recent_base_dirs: Vec<String>,
+    pinned_projects: Vec<String>,
+    last_selected_spec: Option<SelectedSpec>,
+    // Free-form overrides keyed by project path
+    project_overrides: BTreeMap<String, Map<String, Value>>,
+    ui: UiPreferences,
+    // In-progress tasks started or idle for longer than this are reported as stale
+    stale_after_days: u32,
+    // Fields written by a newer version of the app are kept as-is
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedSpec {
+    project_path: String,
+    spec_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiPreferences {
+    theme: Option<String>,
+    hide_specs: bool,
+    specs_sort_column: Option<String>,
+    specs_sort_direction: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            schema_version: SETTINGS_SCHEMA_VERSION,
+            recent_base_dirs: Vec::new(),
+            pinned_projects: Vec::new(),
+            last_selected_spec: None,
+            project_overrides: BTreeMap::new(),
+            ui: UiPreferences::default(),
+            stale_after_days: DEFAULT_STALE_AFTER_DAYS,
+            extra: Map::new(),
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_settings(app: tauri::AppHandle, store: tauri::State<'_, SettingsStore>) -> Result<AppSettings, String> {
+    store.read(&app)
+}
+
+// Applies a JSON merge patch (RFC 7396) to the stored settings: objects are merged
+// recursively, `null` removes a key and any other value replaces it
+#[tauri::command]
+pub async fn update_settings(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, SettingsStore>,
+    patch: Value,
+) -> Result<AppSettings, String> {
+    if !patch.is_object() {
+        return Err("Settings update must be a JSON object".to_string());
+    }
+    
+    store.update(&app, |settings| {
+        let mut document = serde_json::to_value(&*settings)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        merge_patch(&mut document, &patch);
+        
+        let mut updated: AppSettings = serde_json::from_value(document)
+            .map_err(|e| format!("Invalid settings: {}", e))?;
+        updated.schema_version = settings.schema_version;
+        let mut seen = HashSet::new();
+        updated.recent_base_dirs.retain(|dir| seen.insert(dir.clone()));
+        updated.recent_base_dirs.truncate(MAX_RECENT_BASE_DIRS);
+        
+        *settings = updated;
+        Ok(())
+    })
+}
+
+impl SettingsStore {
+    pub(crate) fn read(&self, app: &tauri::AppHandle) -> Result<AppSettings, String> {
+        let mut cached = self.0.lock()
+            .map_err(|e| format!("Failed to lock settings: {}", e))?;
+        
+        if cached.is_none() {
+            *cached = Some(load_settings(&settings_path(app)?)?);
+        }
+        
+        Ok(cached.clone().unwrap_or_default())
+    }
+    
+    pub(crate) fn update<F>(&self, app: &tauri::AppHandle, apply: F) -> Result<AppSettings, String>
+    where
+        F: FnOnce(&mut AppSettings) -> Result<(), String>,
+    {
+        let path = settings_path(app)?;
+        let mut cached = self.0.lock()
+            .map_err(|e| format!("Failed to lock settings: {}", e))?;
+        
+        let mut settings = match cached.take() {
+            Some(settings) => settings,
+            None => load_settings(&path)?,
+        };
+        let original = settings.clone();
+        
+        if let Err(e) = apply(&mut settings) {
+            *cached = Some(original);
+            return Err(e);
+        }
+        
+        if let Err(e) = save_settings(&path, &settings) {
+            *cached = Some(original);
+            return Err(e);
+        }
+        
+        *cached = Some(settings.clone());
+        Ok(settings)
+    }
+}
+
+// Moves a base directory to the front of the recent list
+pub(crate) fn remember_base_dir(app: &tauri::AppHandle, store: &SettingsStore, base_dir: &str) -> Result<(), String> {
+    store.update(app, |settings| {
+        settings.recent_base_dirs.retain(|dir| dir != base_dir);
+        settings.recent_base_dirs.insert(0, base_dir.to_string());
+        settings.recent_base_dirs.truncate(MAX_RECENT_BASE_DIRS);
+        Ok(())
+    })
+    .map(|_| ())
+}
+
+impl AppSettings {
+    pub(crate) fn recent_base_dirs(&self) -> &[String] {
+        &self.recent_base_dirs
+    }
+    
+    pub(crate) fn stale_after_days(&self) -> u32 {
+        self.stale_after_days.max(1)
+    }
+}
+
+fn settings_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(SETTINGS_FILE))
+        .map_err(|e| format!("Failed to resolve app config directory: {}", e))
+}
+
+fn load_settings(path: &Path) -> Result<AppSettings, String> {
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+    
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read settings: {}", e))?;
+    
+    let mut document = match serde_json::from_str::<Value>(&content) {
+        Ok(Value::Object(document)) => document,
+        Ok(_) | Err(_) => {
+            // Keep the unreadable file around rather than silently losing it
+            let backup = path.with_extension("json.invalid");
+            log::warn!("Settings file is not valid JSON, moving it to {}", backup.display());
+            let _ = fs::rename(path, &backup);
+            return Ok(AppSettings::default());
+        }
+    };
+    
+    // Files from before `schema_version` was written have the version 1 layout
+    let version = document.get("schema_version")
+        .and_then(|v| v.as_u64())
+        .map_or(1, |v| v.max(1)) as u32;
+    
+    if version > SETTINGS_SCHEMA_VERSION {
+        log::warn!(
+            "Settings were written by a newer version (schema {}), unknown fields will be preserved",
+            version
+        );
+    } else {
+        for migration in &MIGRATIONS[(version - 1) as usize..] {
+            migration(&mut document);
+        }
+        document.insert("schema_version".to_string(), Value::from(SETTINGS_SCHEMA_VERSION));
+    }
+    
+    serde_json::from_value(Value::Object(document))
+        .map_err(|e| format!("Failed to parse settings: {}", e))
+}
+
+fn save_settings(path: &Path, settings: &AppSettings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    
+    let content = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    write_file_atomic(path, &content)
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let patch = match patch.as_object() {
+        Some(patch) => patch,
+        None => {
+            *target = patch.clone();
+            return;
+        }
+    };
+    
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    
+    if let Some(target) = target.as_object_mut() {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::commands::{kebab_spec_name, write_file_atomic, SpecMetadata};
+use crate::dependencies::{find_spec, project_specs, qualified_id, split_dependency};
+use crate::issue_sync::LINK_FIELD;
+use crate::lifecycle::{derive_status, SpecStatus};
+use crate::roadmap::{project_root, spec_slug};
+use crate::tasks::{load_tasks_file, reset_checklist, save_tasks_file, tasks_file_json, TasksFile};
+
+// Directory under `.agent-sdd/specs/` holding archived specs
+pub(crate) const ARCHIVE_DIR: &str = "_archive";
+// Deleted specs are moved here, next to a JSON file recording where they came from
+const TRASH_DIR: &str = ".trash";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TrashInfo {
+    // Relative to the project root
+    original_path: String,
+    deleted_at: String,
+}
+
+// Moves a spec into `specs/_archive/` and marks it archived, remembering its
+// previous status for `restore_spec`. Returns the new spec path.
+#[tauri::command]
+pub async fn archive_spec(spec_path: String) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (project_dir, spec_id) = spec_location(spec_dir)?;
+    let specs_dir = project_dir.join(".agent-sdd").join("specs");
+    if spec_dir.parent() != Some(specs_dir.as_path()) {
+        return Err("Only specs directly under .agent-sdd/specs can be archived".to_string());
+    }
+    
+    let target = specs_dir.join(ARCHIVE_DIR).join(&spec_id);
+    if target.exists() {
+        return Err(format!("An archived spec named '{}' already exists", spec_id));
+    }
+    
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let previous = tasks_file.status.clone().unwrap_or_default();
+    tasks_file.extra.insert("archived_from".to_string(), Value::String(previous));
+    tasks_file.extra.insert(
+        "archived".to_string(),
+        Value::String(chrono::Local::now().format("%Y-%m-%d").to_string()),
+    );
+    tasks_file.status = Some(SpecStatus::Archived.as_str().to_string());
+    
+    move_and_rewrite(spec_dir, &target, &[("tasks.json", tasks_file_json(&tasks_file)?)])?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Moves an archived spec back into `specs/` with the status it had before
+// archiving, or the status its tasks point to. Returns the new spec path.
+#[tauri::command]
+pub async fn restore_spec(spec_path: String) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (project_dir, spec_id) = spec_location(spec_dir)?;
+    let specs_dir = project_dir.join(".agent-sdd").join("specs");
+    if spec_dir.parent() != Some(specs_dir.join(ARCHIVE_DIR).as_path()) {
+        return Err("Spec is not in the archive".to_string());
+    }
+    
+    let target = specs_dir.join(&spec_id);
+    if target.exists() {
+        return Err(format!("A spec named '{}' already exists", spec_id));
+    }
+    
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let previous = tasks_file.extra.remove("archived_from")
+        .and_then(|status| status.as_str().and_then(SpecStatus::parse))
+        .filter(|status| *status != SpecStatus::Archived);
+    tasks_file.extra.remove("archived");
+    let status = previous.unwrap_or_else(|| {
+        let task_statuses: Vec<&str> = tasks_file.tasks.iter().map(|task| task.status.as_str()).collect();
+        derive_status(&task_statuses)
+    });
+    tasks_file.status = Some(status.as_str().to_string());
+    
+    move_and_rewrite(spec_dir, &target, &[("tasks.json", tasks_file_json(&tasks_file)?)])?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Renames a spec's directory and its `feature`, keeping the date part of the
+// directory name, and points `spec/TASK` dependencies in other specs at the
+// new name. Returns the new spec path.
+#[tauri::command]
+pub async fn rename_spec(spec_path: String, new_name: String) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (_, spec_id) = spec_location(spec_dir)?;
+    let kebab_name = kebab_spec_name(&new_name);
+    if kebab_name.is_empty() {
+        return Err("Spec name is required".to_string());
+    }
+    
+    // Keep whichever end of `YYYY-MM-DD-name` / `name-YYYY-MM-DD` holds the date
+    let slug = spec_slug(&spec_id);
+    let new_id = if slug.len() == spec_id.len() {
+        kebab_name
+    } else if spec_id.starts_with(slug) {
+        format!("{}-{}", kebab_name, &spec_id[slug.len() + 1..])
+    } else {
+        format!("{}-{}", &spec_id[..spec_id.len() - slug.len() - 1], kebab_name)
+    };
+    
+    let target = spec_dir.with_file_name(&new_id);
+    if new_id != spec_id && target.exists() {
+        return Err(format!("A spec named '{}' already exists", new_id));
+    }
+    
+    let specs = project_specs(spec_dir).await?;
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let old_feature = tasks_file.feature.replace(new_name.trim().to_string());
+    retarget_dependencies(&mut tasks_file, &specs, &spec_id, &new_id);
+    let mut files = vec![("tasks.json", tasks_file_json(&tasks_file)?)];
+    
+    // The sdd.md title is the spec name when create_spec wrote it
+    if let (Some(old_feature), Ok(sdd)) = (old_feature, fs::read_to_string(spec_dir.join("sdd.md"))) {
+        if let Some(rest) = sdd.strip_prefix(&format!("# {}\n", old_feature)) {
+            files.push(("sdd.md", format!("# {}\n{}", new_name.trim(), rest)));
+        }
+    }
+    
+    // Rewrite the dependents first so they can be put back if the move fails
+    let mut dependents: Vec<(PathBuf, String)> = Vec::new();
+    let mut result = Ok(());
+    if new_id != spec_id {
+        for spec in specs.iter().filter(|spec| spec.id != spec_id) {
+            let dependent_dir = Path::new(&spec.path);
+            let mut dependent = match load_tasks_file(dependent_dir) {
+                Ok(dependent) => dependent,
+                Err(_) => continue,
+            };
+            if !retarget_dependencies(&mut dependent, &specs, &spec_id, &new_id) {
+                continue;
+            }
+            let original = fs::read_to_string(dependent_dir.join("tasks.json")).unwrap_or_default();
+            result = save_tasks_file(dependent_dir, &dependent);
+            if result.is_err() {
+                break;
+            }
+            dependents.push((dependent_dir.to_path_buf(), original));
+        }
+    }
+    if result.is_ok() {
+        result = move_and_rewrite(spec_dir, &target, &files);
+    }
+    
+    if let Err(e) = result {
+        for (dependent_dir, original) in &dependents {
+            if let Err(undo) = write_file_atomic(&dependent_dir.join("tasks.json"), original) {
+                log::warn!("Failed to restore {} after a failed rename: {}", dependent_dir.display(), undo);
+            }
+        }
+        return Err(e);
+    }
+    
+    if !dependents.is_empty() {
+        log::info!("Renamed {} to {}, updating dependencies in {} specs", spec_id, new_id, dependents.len());
+    }
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Points qualified dependencies on `old_id` at `new_id`. References by the
+// undated name keep that form while it still picks out a single spec. Returns
+// whether anything changed.
+fn retarget_dependencies(tasks_file: &mut TasksFile, specs: &[SpecMetadata], old_id: &str, new_id: &str) -> bool {
+    let new_slug = spec_slug(new_id);
+    let slug_unique = !specs.iter().any(|spec| spec.id != old_id && spec_slug(&spec.id) == new_slug);
+    
+    let mut changed = false;
+    for task in &mut tasks_file.tasks {
+        for dep in &mut task.dependencies {
+            let (spec_ref, task_id) = match split_dependency(dep) {
+                (Some(spec_ref), task_id) => (spec_ref, task_id),
+                (None, _) => continue,
+            };
+            if !find_spec(specs, spec_ref).is_ok_and(|spec| spec.id == old_id) {
+                continue;
+            }
+            let new_ref = if spec_ref != old_id && slug_unique { new_slug } else { new_id };
+            *dep = qualified_id(new_ref, task_id);
+            changed = true;
+        }
+    }
+    changed
+}
+
+// Copies a spec into a new spec dated today, with every task back to pending
+// and the spec status back to planned. Returns the new spec path.
+#[tauri::command]
+pub async fn duplicate_spec(spec_path: String, new_name: Option<String>) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (project_dir, spec_id) = spec_location(spec_dir)?;
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    
+    let name = new_name
+        .filter(|name| !name.trim().is_empty())
+        .or_else(|| tasks_file.feature.clone())
+        .unwrap_or_else(|| spec_slug(&spec_id).replace('-', " "));
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let target = project_dir.join(".agent-sdd").join("specs")
+        .join(format!("{}-{}", today, kebab_spec_name(&name)));
+    if target.exists() {
+        return Err("A spec with this name already exists for today".to_string());
+    }
+    
+    copy_dir(spec_dir, &target)?;
+    
+    tasks_file.feature = Some(name.trim().to_string());
+    tasks_file.status = Some(SpecStatus::Planned.as_str().to_string());
+    tasks_file.created = Some(today);
+    tasks_file.extra.remove("archived");
+    tasks_file.extra.remove("archived_from");
+    for task in &mut tasks_file.tasks {
+        task.status = "pending".to_string();
+        task.completed = None;
+        task.ux_ui_reviewed = None;
+        reset_checklist(&mut task.extra);
+        // The copy isn't tracked by the original's issues
+        task.extra.remove(LINK_FIELD);
+    }
+    save_tasks_file(&target, &tasks_file)?;
+    
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Moves a spec to `.agent-sdd/.trash/` instead of deleting it. Returns the id
+// to pass to `restore_deleted_spec`.
+#[tauri::command]
+pub async fn delete_spec(spec_path: String) -> Result<String, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (project_dir, spec_id) = spec_location(spec_dir)?;
+    let trash_dir = project_dir.join(".agent-sdd").join(TRASH_DIR);
+    fs::create_dir_all(&trash_dir)
+        .map_err(|e| format!("Failed to create trash directory: {}", e))?;
+    
+    let now = chrono::Local::now();
+    let trash_id = format!("{}-{}", now.format("%Y%m%d-%H%M%S"), spec_id);
+    let info = TrashInfo {
+        original_path: relative(project_dir, spec_dir).ok_or("Spec is outside the project")?,
+        deleted_at: now.to_rfc3339(),
+    };
+    let info_json = serde_json::to_string_pretty(&info)
+        .map_err(|e| format!("Failed to serialize trash info: {}", e))?;
+    
+    fs::rename(spec_dir, trash_dir.join(&trash_id))
+        .map_err(|e| format!("Failed to move spec to trash: {}", e))?;
+    write_file_atomic(&trash_dir.join(format!("{}.json", trash_id)), &info_json)?;
+    
+    Ok(trash_id)
+}
+
+#[tauri::command]
+pub async fn restore_deleted_spec(project_path: String, trash_id: String) -> Result<String, String> {
+    let project_dir = Path::new(&project_path);
+    let trash_dir = project_dir.join(".agent-sdd").join(TRASH_DIR);
+    let trashed = trash_dir.join(&trash_id);
+    let info_path = trash_dir.join(format!("{}.json", trash_id));
+    if trash_id.contains(['/', '\\']) || !trashed.is_dir() {
+        return Err(format!("'{}' is not in the trash", trash_id));
+    }
+    
+    let content = fs::read_to_string(&info_path)
+        .map_err(|e| format!("Failed to read trash info: {}", e))?;
+    let info: TrashInfo = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse trash info: {}", e))?;
+    
+    // The info file lives in the project, so don't trust it to point anywhere
+    // but a spec directory
+    let original_path = Path::new(&info.original_path);
+    let specs_dir = Path::new(".agent-sdd").join("specs");
+    if !original_path.components().all(|component| matches!(component, Component::Normal(_)))
+        || !original_path.starts_with(&specs_dir)
+        || original_path == specs_dir
+    {
+        return Err(format!("Cannot restore: '{}' is not a spec directory", info.original_path));
+    }
+    
+    let target: PathBuf = project_dir.join(original_path);
+    if target.exists() {
+        return Err(format!("Cannot restore: {} already exists", info.original_path));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    
+    fs::rename(&trashed, &target).map_err(|e| format!("Failed to restore spec: {}", e))?;
+    if let Err(e) = fs::remove_file(&info_path) {
+        log::warn!("Failed to remove trash info for {}: {}", trash_id, e);
+    }
+    
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Project root and spec id for a spec directory
+pub(crate) fn spec_location(spec_dir: &Path) -> Result<(&Path, String), String> {
+    if !spec_dir.join("tasks.json").exists() {
+        return Err("Not a spec directory: tasks.json not found".to_string());
+    }
+    
+    let spec_id = spec_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or("Invalid spec path")?
+        .to_string();
+    Ok((project_root(spec_dir)?, spec_id))
+}
+
+// Moves a spec directory, then writes the given files (name and new content)
+// inside it at the new location. If a write fails, files already written get
+// their old content back and the directory is moved back, so a failure never
+// leaves a spec half moved. The move is a plain rename: git picks it up as a
+// rename on the next commit without touching the user's index.
+fn move_and_rewrite(from: &Path, to: &Path, files: &[(&str, String)]) -> Result<(), String> {
+    let originals: Vec<Option<String>> = files.iter()
+        .map(|(name, _)| fs::read_to_string(from.join(name)).ok())
+        .collect();
+    
+    if from != to {
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::rename(from, to).map_err(|e| format!("Failed to move spec directory: {}", e))?;
+    }
+    
+    for (index, (name, content)) in files.iter().enumerate() {
+        if let Err(e) = write_file_atomic(&to.join(name), content) {
+            for ((name, _), original) in files[..index].iter().zip(&originals) {
+                if let Some(original) = original {
+                    if let Err(undo) = write_file_atomic(&to.join(name), original) {
+                        log::warn!("Failed to restore {} after a failed update: {}", name, undo);
+                    }
+                }
+            }
+            if from != to {
+                if let Err(undo) = fs::rename(to, from) {
+                    log::warn!("Failed to move {} back after a failed update: {}", to.display(), undo);
+                }
+            }
+            return Err(e);
+        }
+    }
+    
+    Ok(())
+}
+
+fn relative(project_dir: &Path, path: &Path) -> Option<String> {
+    path.strip_prefix(project_dir).ok().map(|rel| rel.to_string_lossy().replace('\\', "/"))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), String> {
+    fs::create_dir_all(to).map_err(|e| format!("Failed to create {}: {}", to.display(), e))?;
+    
+    let entries = fs::read_dir(from).map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+        if path.is_dir() {
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, &target)
+                .map_err(|e| format!("Failed to copy {}: {}", path.display(), e))?;
+        }
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn duplicate_spec_unticks_checklists() {
+        let project_dir = std::env::temp_dir().join(format!("agent-sdd-duplicate-{}", std::process::id()));
+        let spec_dir = project_dir.join(".agent-sdd").join("specs").join("2024-01-15-search");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("tasks.json"), r#"{
+            "feature": "Search",
+            "tasks": [{
+                "id": "TASK-001",
+                "name": "Index",
+                "status": "in_progress",
+                "dependencies": [],
+                "checklist": [
+                    {"text": "Schema", "done": true},
+                    {"text": "Backfill", "status": "completed"},
+                    "Docs"
+                ]
+            }]
+        }"#).unwrap();
+        
+        let copy = duplicate_spec(spec_dir.to_string_lossy().to_string(), Some("Search copy".to_string())).await;
+        let copy = load_tasks_file(Path::new(&copy.unwrap()));
+        fs::remove_dir_all(&project_dir).unwrap();
+        
+        let task = &copy.unwrap().tasks[0];
+        assert_eq!(task.status, "pending");
+        assert_eq!(task.extra["checklist"], serde_json::json!([
+            {"text": "Schema", "done": false},
+            {"text": "Backfill", "status": "pending"},
+            "Docs"
+        ]));
+    }
+}
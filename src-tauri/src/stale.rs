@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::analytics::parse_day;
+use crate::commands::{scan_specs, SpecMetadata};
+use crate::git::{file_revisions, last_commit_date};
+use crate::history::read_executions;
+use crate::settings::SettingsStore;
+use crate::tasks::{load_tasks_file, save_tasks_file};
+
+pub(crate) const DEFAULT_STALE_AFTER_DAYS: u32 = 7;
+
+// An in-progress task that has been running too long or has gone quiet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleTask {
+    pub(crate) spec_id: String,
+    pub(crate) spec_path: String,
+    pub(crate) task_id: String,
+    pub(crate) task_name: String,
+    // Best estimate of when the task was set in progress: the committed
+    // tasks.json history, else the task's first recorded execution
+    pub(crate) in_progress_since: Option<String>,
+    // Latest of the task's executions, tasks.json edits and commits to the spec
+    pub(crate) last_activity: Option<String>,
+    pub(crate) days_in_progress: Option<i64>,
+    pub(crate) days_inactive: Option<i64>,
+    pub(crate) reasons: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StaleTaskReport {
+    stale_after_days: u32,
+    in_progress: usize,
+    tasks: Vec<StaleTask>,
+}
+
+// `stale_after_days` overrides the threshold from the app settings
+#[tauri::command]
+pub async fn get_stale_tasks(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsStore>,
+    project_path: String,
+    stale_after_days: Option<u32>,
+) -> Result<StaleTaskReport, String> {
+    let project_dir = PathBuf::from(&project_path);
+    if !project_dir.join(".agent-sdd").is_dir() {
+        return Err("Project does not contain .agent-sdd directory".to_string());
+    }
+    
+    let stale_after_days = stale_threshold(&app, &settings, stale_after_days);
+    let specs = scan_specs(project_path.clone(), None).await?;
+    let in_progress = specs.iter()
+        .flat_map(|spec| &spec.tasks)
+        .filter(|task| task.status == "in_progress")
+        .count();
+    
+    // Walking the history spawns git, so keep it off the async runtime
+    let tasks = tokio::task::spawn_blocking(move || find_stale_tasks(&project_dir, &specs, stale_after_days))
+        .await
+        .map_err(|e| format!("Stale task detection failed: {}", e))?;
+    
+    Ok(StaleTaskReport {
+        stale_after_days,
+        in_progress,
+        tasks,
+    })
+}
+
+// Sets the given tasks back to pending, skipping any that are no longer in
+// progress. Returns the ids that were reset.
+#[tauri::command]
+pub async fn reset_stale_tasks(spec_path: String, task_ids: Vec<String>) -> Result<Vec<String>, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    
+    let mut reset = Vec::new();
+    for task in tasks_file.tasks.iter_mut() {
+        if task.status == "in_progress" && task_ids.contains(&task.id) {
+            task.status = "pending".to_string();
+            reset.push(task.id.clone());
+        }
+    }
+    
+    if !reset.is_empty() {
+        save_tasks_file(spec_dir, &tasks_file)?;
+    }
+    Ok(reset)
+}
+
+// The threshold to use: an explicit value, else the one in the app settings.
+// Unreadable settings fall back to the default rather than failing the caller.
+pub(crate) fn stale_threshold(app: &tauri::AppHandle, store: &SettingsStore, stale_after_days: Option<u32>) -> u32 {
+    let stale_after_days = stale_after_days.unwrap_or_else(|| match store.read(app) {
+        Ok(settings) => settings.stale_after_days(),
+        Err(e) => {
+            log::warn!("Failed to read stale task threshold from settings: {}", e);
+            DEFAULT_STALE_AFTER_DAYS
+        }
+    });
+    stale_after_days.max(1)
+}
+
+// Flags in-progress tasks that were started more than `stale_after_days` ago,
+// or that have had no activity for that long
+pub(crate) fn find_stale_tasks(project_dir: &Path, specs: &[SpecMetadata], stale_after_days: u32) -> Vec<StaleTask> {
+    let today = Utc::now().date_naive();
+    let threshold = i64::from(stale_after_days);
+    
+    // First and last execution of any command per (spec, task)
+    let mut executions: HashMap<(String, String), (NaiveDate, NaiveDate)> = HashMap::new();
+    for record in read_executions(&project_dir.join(".agent-sdd")) {
+        if let Some(date) = parse_day(&record.timestamp) {
+            let entry = executions.entry((record.spec_id, record.task_id)).or_insert((date, date));
+            entry.0 = entry.0.min(date);
+            entry.1 = entry.1.max(date);
+        }
+    }
+    
+    let mut stale = Vec::new();
+    for spec in specs {
+        if !spec.tasks.iter().any(|task| task.status == "in_progress") {
+            continue;
+        }
+        
+        let spec_dir = Path::new(&spec.path);
+        let tasks_file = spec_dir.join("tasks.json");
+        let modified = fs::metadata(&tasks_file)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(|modified| DateTime::<Utc>::from(modified).date_naive());
+        let committed = last_commit_date(project_dir, spec_dir);
+        let started = in_progress_since(project_dir, &tasks_file);
+        
+        for task in spec.tasks.iter().filter(|task| task.status == "in_progress") {
+            let runs = executions.get(&(spec.id.clone(), task.id.clone()));
+            let since = started.get(&task.id).copied().or(runs.map(|(first, _)| *first));
+            let last_activity = [runs.map(|(_, last)| *last), modified, committed]
+                .into_iter()
+                .flatten()
+                .max();
+            
+            let days_in_progress = since.map(|date| (today - date).num_days());
+            let days_inactive = last_activity.map(|date| (today - date).num_days());
+            
+            let mut reasons = Vec::new();
+            if let Some(days) = days_in_progress.filter(|days| *days > threshold) {
+                reasons.push(format!("In progress for {} days", days));
+            }
+            match days_inactive {
+                Some(days) if days > threshold => reasons.push(format!("No activity for {} days", days)),
+                Some(_) => {}
+                None => reasons.push("No recorded activity".to_string()),
+            }
+            if reasons.is_empty() {
+                continue;
+            }
+            
+            stale.push(StaleTask {
+                spec_id: spec.id.clone(),
+                spec_path: spec.path.clone(),
+                task_id: task.id.clone(),
+                task_name: task.name.clone(),
+                in_progress_since: since.map(|date| date.to_string()),
+                last_activity: last_activity.map(|date| date.to_string()),
+                days_in_progress,
+                days_inactive,
+                reasons,
+            });
+        }
+    }
+    
+    stale
+}
+
+// When each task that is in progress in the latest commit of tasks.json was
+// set in progress, walking back through the revisions where it stayed so
+fn in_progress_since(project_dir: &Path, tasks_file: &Path) -> HashMap<String, NaiveDate> {
+    let mut since = HashMap::new();
+    let mut open: Option<HashSet<String>> = None;
+    
+    for (date, content) in file_revisions(project_dir, tasks_file).into_iter().rev() {
+        let in_progress = in_progress_ids(&content);
+        let open = open.get_or_insert_with(|| in_progress.clone());
+        open.retain(|id| in_progress.contains(id));
+        if open.is_empty() {
+            break;
+        }
+        for id in open.iter() {
+            since.insert(id.clone(), date);
+        }
+    }
+    
+    since
+}
+
+fn in_progress_ids(content: &str) -> HashSet<String> {
+    let tasks_json: serde_json::Value = match serde_json::from_str(content) {
+        Ok(json) => json,
+        Err(_) => return HashSet::new(),
+    };
+    
+    tasks_json["tasks"].as_array()
+        .into_iter()
+        .flatten()
+        .filter(|task| task["status"].as_str() == Some("in_progress"))
+        .filter_map(|task| task["id"].as_str().map(|id| id.to_string()))
+        .collect()
+}
@@ -0,0 +1,413 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::tasks::{load_tasks_file, merge_task_fields, save_tasks_file, validate_tasks, TaskRecord};
+
+// Columns written on export, in order; extra fields follow alphabetically
+const TASK_COLUMNS: [&str; 8] = [
+    "id",
+    "name",
+    "description",
+    "status",
+    "effort",
+    "dependencies",
+    "completed",
+    "ux_ui_reviewed",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExchangeFormat {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportPreview {
+    dry_run: bool,
+    applied: bool,
+    added: Vec<TaskChange>,
+    updated: Vec<TaskChange>,
+    removed: Vec<String>,
+    unchanged: usize,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskChange {
+    id: String,
+    name: String,
+    changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FieldChange {
+    field: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+#[tauri::command]
+pub async fn export_tasks(spec_path: String, output_path: String, format: Option<String>) -> Result<String, String> {
+    let tasks_file = load_tasks_file(Path::new(&spec_path))?;
+    let format = resolve_format(format.as_deref(), &output_path)?;
+    
+    let content = match format {
+        ExchangeFormat::Csv => tasks_to_csv(&tasks_file.tasks),
+        ExchangeFormat::JsonLines => {
+            let mut out = String::new();
+            for task in &tasks_file.tasks {
+                let line = serde_json::to_string(task)
+                    .map_err(|e| format!("Failed to serialize task {}: {}", task.id, e))?;
+                out.push_str(&line);
+                out.push('\n');
+            }
+            out
+        }
+    };
+    
+    fs::write(&output_path, content)
+        .map_err(|e| format!("Failed to write export file: {}", e))?;
+    
+    Ok(format!("Exported {} tasks to {}", tasks_file.tasks.len(), output_path))
+}
+
+// Merges tasks from a CSV or JSON Lines file into a spec's tasks.json. Rows are
+// matched to tasks by id; only the columns present in the file are changed, so
+// fields the file doesn't mention (including ones TaskInfo doesn't model) are
+// kept. With `remove_missing`, tasks absent from the file are deleted.
+#[tauri::command]
+pub async fn import_tasks(
+    spec_path: String,
+    input_path: String,
+    format: Option<String>,
+    column_map: Option<HashMap<String, String>>,
+    remove_missing: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<ImportPreview, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let format = resolve_format(format.as_deref(), &input_path)?;
+    let dry_run = dry_run.unwrap_or(true);
+    let remove_missing = remove_missing.unwrap_or(false);
+    
+    let content = fs::read_to_string(&input_path)
+        .map_err(|e| format!("Failed to read import file: {}", e))?;
+    
+    let column_map: HashMap<String, String> = column_map.unwrap_or_default()
+        .into_iter()
+        .map(|(column, field)| (normalize_column(&column), normalize_column(&field)))
+        .collect();
+    
+    let rows = match format {
+        ExchangeFormat::Csv => csv_rows(&content, &column_map)?,
+        ExchangeFormat::JsonLines => json_lines_rows(&content, &column_map)?,
+    };
+    
+    let mut preview = ImportPreview {
+        dry_run,
+        applied: false,
+        added: Vec::new(),
+        updated: Vec::new(),
+        removed: Vec::new(),
+        unchanged: 0,
+        errors: Vec::new(),
+    };
+    
+    let mut result: Vec<TaskRecord> = tasks_file.tasks.clone();
+    let positions: HashMap<String, usize> = result.iter()
+        .enumerate()
+        .map(|(i, task)| (task.id.clone(), i))
+        .collect();
+    let mut imported_ids = HashSet::new();
+    
+    for (row_number, row) in rows.into_iter().enumerate() {
+        let id = match row.get("id").and_then(|id| id.as_str()).map(str::trim) {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => {
+                preview.errors.push(format!("Row {} has no task id", row_number + 1));
+                continue;
+            }
+        };
+        if !imported_ids.insert(id.clone()) {
+            preview.errors.push(format!("Task '{}' appears more than once in the import", id));
+            continue;
+        }
+        
+        let existing = positions.get(&id).map(|&i| &result[i]);
+        let merged = match merge_task_fields(existing, &row) {
+            Ok(merged) => merged,
+            Err(e) => {
+                preview.errors.push(format!("Task '{}': {}", id, e));
+                continue;
+            }
+        };
+        
+        match existing {
+            Some(existing) => {
+                let changes = diff_tasks(Some(existing), &merged);
+                if changes.is_empty() {
+                    preview.unchanged += 1;
+                } else {
+                    preview.updated.push(TaskChange { id: id.clone(), name: merged.name.clone(), changes });
+                    result[positions[&id]] = merged;
+                }
+            }
+            None => {
+                preview.added.push(TaskChange {
+                    id: id.clone(),
+                    name: merged.name.clone(),
+                    changes: diff_tasks(None, &merged),
+                });
+                result.push(merged);
+            }
+        }
+    }
+    
+    if remove_missing {
+        preview.removed = tasks_file.tasks.iter()
+            .filter(|task| !imported_ids.contains(&task.id))
+            .map(|task| task.id.clone())
+            .collect();
+        result.retain(|task| imported_ids.contains(&task.id));
+    }
+    
+    preview.errors.extend(validate_tasks(&result));
+    
+    if dry_run {
+        return Ok(preview);
+    }
+    if !preview.errors.is_empty() {
+        return Err(format!("Import has errors and was not applied:\n{}", preview.errors.join("\n")));
+    }
+    
+    tasks_file.tasks = result;
+    save_tasks_file(spec_dir, &tasks_file)?;
+    preview.applied = true;
+    
+    Ok(preview)
+}
+
+fn resolve_format(format: Option<&str>, path: &str) -> Result<ExchangeFormat, String> {
+    let format = match format {
+        Some(format) => format.to_lowercase(),
+        None => Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase())
+            .unwrap_or_default(),
+    };
+    
+    match format.as_str() {
+        "csv" => Ok(ExchangeFormat::Csv),
+        "jsonl" | "ndjson" | "json_lines" => Ok(ExchangeFormat::JsonLines),
+        other => Err(format!("Unsupported task format '{}': expected csv or jsonl", other)),
+    }
+}
+
+fn diff_tasks(before: Option<&TaskRecord>, after: &TaskRecord) -> Vec<FieldChange> {
+    let as_map = |task: Option<&TaskRecord>| -> Map<String, Value> {
+        match task.map(serde_json::to_value) {
+            Some(Ok(Value::Object(map))) => map,
+            _ => Map::new(),
+        }
+    };
+    
+    let before = as_map(before);
+    let after = as_map(Some(after));
+    let fields: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    
+    fields.into_iter()
+        .filter(|field| before.get(*field) != after.get(*field))
+        .map(|field| FieldChange {
+            field: field.clone(),
+            before: before.get(field).cloned(),
+            after: after.get(field).cloned(),
+        })
+        .collect()
+}
+
+fn tasks_to_csv(tasks: &[TaskRecord]) -> String {
+    let extra_columns: BTreeSet<&String> = tasks.iter().flat_map(|task| task.extra.keys()).collect();
+    
+    let mut header: Vec<String> = TASK_COLUMNS.iter().map(|c| c.to_string()).collect();
+    header.extend(extra_columns.iter().map(|c| c.to_string()));
+    
+    let mut out = csv_line(&header);
+    for task in tasks {
+        let mut cells = vec![
+            task.id.clone(),
+            task.name.clone(),
+            task.description.clone(),
+            task.status.clone(),
+            task.effort.clone(),
+            task.dependencies.join("; "),
+            task.completed.clone().unwrap_or_default(),
+            task.ux_ui_reviewed.map(|r| r.to_string()).unwrap_or_default(),
+        ];
+        // Extra fields are written as JSON, strings included, so that a string
+        // like "123" doesn't come back as a number
+        for column in &extra_columns {
+            cells.push(task.extra.get(*column).map(Value::to_string).unwrap_or_default());
+        }
+        out.push_str(&csv_line(&cells));
+    }
+    
+    out
+}
+
+fn csv_line(cells: &[String]) -> String {
+    let escaped: Vec<String> = cells.iter()
+        .map(|cell| {
+            if cell.contains(',') || cell.contains('"') || cell.contains('\n') || cell.contains('\r') {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect();
+    format!("{}\n", escaped.join(","))
+}
+
+// RFC 4180 parsing: quoted cells may contain commas, newlines and doubled quotes
+fn parse_csv(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    cell.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                c => cell.push(c),
+            }
+            continue;
+        }
+        
+        match c {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut cell)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut cell));
+                records.push(std::mem::take(&mut record));
+            }
+            c => cell.push(c),
+        }
+    }
+    
+    if in_quotes {
+        return Err("CSV has an unterminated quoted field".to_string());
+    }
+    if !cell.is_empty() || !record.is_empty() {
+        record.push(cell);
+        records.push(record);
+    }
+    
+    // Spreadsheets often leave blank trailing lines
+    records.retain(|record| record.iter().any(|cell| !cell.trim().is_empty()));
+    Ok(records)
+}
+
+fn csv_rows(content: &str, column_map: &HashMap<String, String>) -> Result<Vec<Map<String, Value>>, String> {
+    let mut records = parse_csv(content)?.into_iter();
+    let header: Vec<String> = match records.next() {
+        Some(header) => header.iter().map(|column| map_column(column, column_map)).collect(),
+        None => return Ok(Vec::new()),
+    };
+    
+    if !header.iter().any(|column| column == "id") {
+        return Err("CSV has no id column; map one with column_map".to_string());
+    }
+    
+    let mut rows = Vec::new();
+    for record in records {
+        let mut row = Map::new();
+        for (column, cell) in header.iter().zip(record.iter()) {
+            if let Some(value) = csv_cell_value(column, cell) {
+                row.insert(column.clone(), value);
+            }
+        }
+        rows.push(row);
+    }
+    
+    Ok(rows)
+}
+
+// Converts a CSV cell into the JSON value stored in tasks.json. Returns None
+// when the cell shouldn't change the field at all.
+fn csv_cell_value(column: &str, cell: &str) -> Option<Value> {
+    let cell = cell.trim();
+    
+    match column {
+        "status" if cell.is_empty() => None,
+        "dependencies" => Some(Value::Array(
+            cell.split([';', ','])
+                .map(str::trim)
+                .filter(|dep| !dep.is_empty())
+                .map(|dep| Value::String(dep.to_string()))
+                .collect(),
+        )),
+        "ux_ui_reviewed" => match cell.to_lowercase().as_str() {
+            "" => Some(Value::Null),
+            "true" | "yes" | "y" | "1" => Some(Value::Bool(true)),
+            _ => Some(Value::Bool(false)),
+        },
+        "completed" if cell.is_empty() => Some(Value::Null),
+        "id" | "name" | "description" | "status" | "effort" | "completed" => Some(Value::String(cell.to_string())),
+        _ if cell.is_empty() => Some(Value::Null),
+        // Extra columns hold JSON as export writes it; anything that doesn't
+        // parse was typed in by hand and is kept as text
+        _ => Some(serde_json::from_str::<Value>(cell).unwrap_or_else(|_| Value::String(cell.to_string()))),
+    }
+}
+
+fn json_lines_rows(content: &str, column_map: &HashMap<String, String>) -> Result<Vec<Map<String, Value>>, String> {
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| match serde_json::from_str::<Value>(line) {
+            Ok(Value::Object(object)) => Ok(object.into_iter()
+                .map(|(key, value)| (map_column(&key, column_map), value))
+                .collect()),
+            Ok(_) => Err(format!("Line {} is not a JSON object", i + 1)),
+            Err(e) => Err(format!("Line {} is not valid JSON: {}", i + 1, e)),
+        })
+        .collect()
+}
+
+// Resolves a column header to a task field: explicit mapping first, then common
+// spreadsheet names. Anything else is treated as an extra field of that name.
+fn map_column(column: &str, column_map: &HashMap<String, String>) -> String {
+    let normalized = normalize_column(column);
+    if let Some(field) = column_map.get(&normalized) {
+        return field.clone();
+    }
+    
+    match normalized.as_str() {
+        "task_id" | "key" => "id",
+        "title" | "task" | "summary" => "name",
+        "desc" | "details" => "description",
+        "state" => "status",
+        "estimate" | "size" => "effort",
+        "deps" | "depends_on" | "blocked_by" => "dependencies",
+        "completed_date" | "completed_on" | "done_date" => "completed",
+        "reviewed" | "ux_reviewed" => "ux_ui_reviewed",
+        _ if TASK_COLUMNS.contains(&normalized.as_str()) => normalized.as_str(),
+        // Unknown columns keep their original spelling so extra fields round-trip
+        _ => return column.trim().to_string(),
+    }
+    .to_string()
+}
+
+fn normalize_column(column: &str) -> String {
+    column.trim().to_lowercase().replace([' ', '-'], "_")
+}
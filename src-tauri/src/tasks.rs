@@ -0,0 +1,702 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::commands::{write_file_atomic, SpecMetadata};
+use crate::dependencies::{find_spec, project_specs, qualified_id, split_dependency};
+use crate::lifecycle::advance_status;
+use crate::roadmap::{draft_progress_log_entry, ProgressLogEntry};
+use crate::specs::spec_location;
+
+pub(crate) const TASK_STATUSES: [&str; 3] = ["pending", "in_progress", "completed"];
+
+// Typed view of a spec's tasks.json. Unlike TaskInfo, which is a read-only
+// summary, this round-trips: any field it doesn't model is kept in `extra`
+// and written back unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TasksFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) phase: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) feature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) created: Option<String>,
+    #[serde(default)]
+    pub(crate) tasks: Vec<TaskRecord>,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+// Serialized by hand so the optional fields are only written when set or when
+// the file had them, keeping `"description": ""` or `"completed": null` as is
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct TaskRecord {
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) description: String,
+    #[serde(default = "default_task_status")]
+    pub(crate) status: String,
+    #[serde(default)]
+    pub(crate) completed: Option<String>,
+    #[serde(default)]
+    pub(crate) dependencies: Vec<String>,
+    #[serde(default)]
+    pub(crate) effort: String,
+    #[serde(default)]
+    pub(crate) ux_ui_reviewed: Option<bool>,
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+    // Which of OPTIONAL_TASK_FIELDS the task had in tasks.json; set by
+    // `with_present_fields`
+    #[serde(skip)]
+    pub(crate) present_fields: Vec<String>,
+}
+
+// TaskRecord fields left out of tasks.json when empty, unless the file had them
+const OPTIONAL_TASK_FIELDS: [&str; 4] = ["description", "completed", "effort", "ux_ui_reviewed"];
+
+impl Serialize for TaskRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let present = |field: &str| self.present_fields.iter().any(|present| present == field);
+        
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("name", &self.name)?;
+        if !self.description.is_empty() || present("description") {
+            map.serialize_entry("description", &self.description)?;
+        }
+        map.serialize_entry("status", &self.status)?;
+        if self.completed.is_some() || present("completed") {
+            map.serialize_entry("completed", &self.completed)?;
+        }
+        map.serialize_entry("dependencies", &self.dependencies)?;
+        if !self.effort.is_empty() || present("effort") {
+            map.serialize_entry("effort", &self.effort)?;
+        }
+        if self.ux_ui_reviewed.is_some() || present("ux_ui_reviewed") {
+            map.serialize_entry("ux_ui_reviewed", &self.ux_ui_reviewed)?;
+        }
+        for (key, value) in &self.extra {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl TaskRecord {
+    // Remembers which optional fields `document`, the task as it was read, has
+    fn with_present_fields(mut self, document: &Value) -> Self {
+        self.present_fields = OPTIONAL_TASK_FIELDS.iter()
+            .filter(|field| document.get(**field).is_some())
+            .map(|field| field.to_string())
+            .collect();
+        self
+    }
+}
+
+// Task fields that may hold a checklist, in the order they're looked up
+const CHECKLIST_FIELDS: [&str; 2] = ["checklist", "subtasks"];
+
+// One entry of a task's optional checklist. In tasks.json an entry is either a
+// plain string (not done) or an object with a text and a `done` flag or status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub(crate) text: String,
+    pub(crate) done: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskStatusUpdate {
+    task_id: String,
+    status: String,
+    spec_completed: bool,
+    // Drafted when this update completed the spec's last task; the frontend
+    // asks before passing it to `append_progress_log`
+    progress_log_entry: Option<ProgressLogEntry>,
+}
+
+#[tauri::command]
+pub async fn update_task_status(
+    spec_path: String,
+    task_id: String,
+    status: String,
+    completed_date: Option<String>,
+) -> Result<TaskStatusUpdate, String> {
+    if !TASK_STATUSES.contains(&status.as_str()) {
+        return Err(format!("Unknown task status '{}' (expected one of {})", status, TASK_STATUSES.join(", ")));
+    }
+    
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let was_completed = all_completed(&tasks_file.tasks);
+    
+    let task = tasks_file.tasks.iter_mut()
+        .find(|task| task.id == task_id)
+        .ok_or_else(|| format!("Task '{}' not found", task_id))?;
+    task.status = status.clone();
+    task.completed = if status == "completed" {
+        Some(completed_date.unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d").to_string()))
+    } else {
+        None
+    };
+    
+    advance_status(&mut tasks_file);
+    save_tasks_file(spec_dir, &tasks_file)?;
+    
+    let spec_completed = all_completed(&tasks_file.tasks);
+    let progress_log_entry = if spec_completed && !was_completed {
+        match draft_progress_log_entry(spec_dir).await {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("Failed to draft progress log entry: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    
+    Ok(TaskStatusUpdate {
+        task_id,
+        status,
+        spec_completed,
+        progress_log_entry,
+    })
+}
+
+// Adds a task with the next id in the spec's sequence (TASK-005 after TASK-004).
+// `fields` holds the task's fields other than the id; `position` inserts it at
+// that index instead of appending.
+#[tauri::command]
+pub async fn add_task(
+    spec_path: String,
+    fields: Map<String, Value>,
+    position: Option<usize>,
+) -> Result<TaskRecord, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let before = validate_tasks(&tasks_file.tasks);
+    
+    let mut fields = fields;
+    fields.insert("id".to_string(), Value::String(next_task_id(&tasks_file.tasks)));
+    let mut task = merge_task_fields(None, &fields)?;
+    if task.name.trim().is_empty() {
+        return Err("Task name is required".to_string());
+    }
+    if task.status == "completed" && task.completed.is_none() {
+        task.completed = Some(chrono::Local::now().format("%Y-%m-%d").to_string());
+    }
+    
+    let index = position.unwrap_or(tasks_file.tasks.len()).min(tasks_file.tasks.len());
+    tasks_file.tasks.insert(index, task.clone());
+    
+    save_if_valid(spec_dir, &mut tasks_file, &before)?;
+    Ok(task)
+}
+
+// Changes the given fields of a task, leaving the rest alone. A null value
+// removes a field. The id can't be changed.
+#[tauri::command]
+pub async fn update_task(
+    spec_path: String,
+    task_id: String,
+    fields: Map<String, Value>,
+) -> Result<TaskRecord, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let before = validate_tasks(&tasks_file.tasks);
+    
+    if fields.get("id").is_some_and(|id| id.as_str() != Some(task_id.as_str())) {
+        return Err("Task ids can't be changed".to_string());
+    }
+    
+    let index = tasks_file.tasks.iter()
+        .position(|task| task.id == task_id)
+        .ok_or_else(|| format!("Task '{}' not found", task_id))?;
+    let mut task = merge_task_fields(Some(&tasks_file.tasks[index]), &fields)?;
+    
+    // Keep the completion date in step with the status unless it was set explicitly
+    if !fields.contains_key("completed") {
+        if task.status != "completed" {
+            task.completed = None;
+        } else if task.completed.is_none() {
+            task.completed = Some(chrono::Local::now().format("%Y-%m-%d").to_string());
+        }
+    }
+    
+    tasks_file.tasks[index] = task.clone();
+    advance_status(&mut tasks_file);
+    save_if_valid(spec_dir, &mut tasks_file, &before)?;
+    Ok(task)
+}
+
+// Removes a task. Tasks that depend on it, in this spec or others, block the
+// delete unless `rewire` is set, in which case they take over its
+// dependencies. Returns the ids of the tasks that were rewired, qualified with
+// the spec for tasks in other specs.
+#[tauri::command]
+pub async fn delete_task(spec_path: String, task_id: String, rewire: Option<bool>) -> Result<Vec<String>, String> {
+    let spec_dir = Path::new(&spec_path);
+    let (_, spec_id) = spec_location(spec_dir)?;
+    let specs = project_specs(spec_dir).await?;
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    let before = validate_tasks(&tasks_file.tasks);
+    
+    let index = tasks_file.tasks.iter()
+        .position(|task| task.id == task_id)
+        .ok_or_else(|| format!("Task '{}' not found", task_id))?;
+    let removed = tasks_file.tasks.remove(index);
+    let is_removed = |from_id: &str, dep: &str| depends_on(&specs, from_id, dep, &spec_id, &task_id);
+    
+    let local: Vec<String> = tasks_file.tasks.iter()
+        .filter(|task| task.dependencies.iter().any(|dep| is_removed(&spec_id, dep)))
+        .map(|task| task.id.clone())
+        .collect();
+    let others: Vec<&SpecMetadata> = specs.iter()
+        .filter(|spec| spec.id != spec_id)
+        .filter(|spec| spec.tasks.iter().any(|task| task.dependencies.iter().any(|dep| is_removed(&spec.id, dep))))
+        .collect();
+    let mut dependents = local.clone();
+    for spec in &others {
+        for task in spec.tasks.iter().filter(|task| task.dependencies.iter().any(|dep| is_removed(&spec.id, dep))) {
+            dependents.push(qualified_id(&spec.id, &task.id));
+        }
+    }
+    if !dependents.is_empty() && !rewire.unwrap_or(false) {
+        return Err(format!(
+            "Task '{}' is a dependency of {}; delete with rewire to pass its dependencies on to them",
+            task_id,
+            dependents.join(", ")
+        ));
+    }
+    
+    for task in tasks_file.tasks.iter_mut().filter(|task| local.contains(&task.id)) {
+        task.dependencies.retain(|dep| !is_removed(&spec_id, dep));
+        for dep in &removed.dependencies {
+            if !task.dependencies.contains(dep) && !depends_on(&specs, &spec_id, dep, &spec_id, &task.id) {
+                task.dependencies.push(dep.clone());
+            }
+        }
+    }
+    
+    // Rewire the other specs first so they can be put back if this one can't
+    // be saved. Bare ids in the removed task's dependencies are relative to
+    // this spec, so they're qualified on the way over.
+    let inherited: Vec<String> = removed.dependencies.iter()
+        .map(|dep| match split_dependency(dep) {
+            (None, dep_task) => qualified_id(&spec_id, dep_task),
+            (Some(_), _) => dep.clone(),
+        })
+        .collect();
+    let mut rewired: Vec<(PathBuf, String)> = Vec::new();
+    let mut result = Ok(());
+    for spec in &others {
+        let dependent_dir = Path::new(&spec.path);
+        let original = match fs::read_to_string(dependent_dir.join("tasks.json")) {
+            Ok(original) => original,
+            Err(e) => {
+                result = Err(format!("Failed to read tasks.json of {}: {}", spec.id, e));
+                break;
+            }
+        };
+        let mut dependent = match load_tasks_file(dependent_dir) {
+            Ok(dependent) => dependent,
+            Err(e) => {
+                result = Err(e);
+                break;
+            }
+        };
+        for task in &mut dependent.tasks {
+            if !task.dependencies.iter().any(|dep| is_removed(&spec.id, dep)) {
+                continue;
+            }
+            task.dependencies.retain(|dep| !is_removed(&spec.id, dep));
+            for dep in &inherited {
+                if !task.dependencies.contains(dep) && !depends_on(&specs, &spec.id, dep, &spec.id, &task.id) {
+                    task.dependencies.push(dep.clone());
+                }
+            }
+        }
+        result = save_tasks_file(dependent_dir, &dependent);
+        if result.is_err() {
+            break;
+        }
+        rewired.push((dependent_dir.to_path_buf(), original));
+    }
+    if result.is_ok() {
+        result = save_if_valid(spec_dir, &mut tasks_file, &before);
+    }
+    
+    if let Err(e) = result {
+        for (dependent_dir, original) in &rewired {
+            if let Err(undo) = write_file_atomic(&dependent_dir.join("tasks.json"), original) {
+                log::warn!("Failed to restore {} after a failed task delete: {}", dependent_dir.display(), undo);
+            }
+        }
+        return Err(e);
+    }
+    Ok(dependents)
+}
+
+// Whether `dep`, declared by a task in spec `from_id`, points at task `task_id`
+// of spec `spec_id`
+fn depends_on(specs: &[SpecMetadata], from_id: &str, dep: &str, spec_id: &str, task_id: &str) -> bool {
+    let (spec_ref, dep_task) = split_dependency(dep);
+    dep_task == task_id && match spec_ref {
+        None => from_id == spec_id,
+        Some(spec_ref) => spec_ref == spec_id || find_spec(specs, spec_ref).is_ok_and(|spec| spec.id == spec_id),
+    }
+}
+
+// Puts the tasks in the given order, which must list every task id exactly once
+#[tauri::command]
+pub async fn reorder_tasks(spec_path: String, task_ids: Vec<String>) -> Result<Vec<TaskRecord>, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    
+    let mut by_id: HashMap<String, TaskRecord> = tasks_file.tasks.drain(..)
+        .map(|task| (task.id.clone(), task))
+        .collect();
+    let mut ordered = Vec::with_capacity(by_id.len());
+    for id in &task_ids {
+        let task = by_id.remove(id)
+            .ok_or_else(|| format!("Task '{}' is unknown or listed twice", id))?;
+        ordered.push(task);
+    }
+    if !by_id.is_empty() {
+        let mut missing: Vec<String> = by_id.into_keys().collect();
+        missing.sort();
+        return Err(format!("The new order is missing tasks: {}", missing.join(", ")));
+    }
+    
+    tasks_file.tasks = ordered;
+    save_tasks_file(spec_dir, &tasks_file)?;
+    Ok(tasks_file.tasks)
+}
+
+// Ticks or unticks one checklist entry of a task, flipping it when `done` isn't
+// given. Entries keep the shape they were written in; plain strings become
+// objects since they can't record being done.
+#[tauri::command]
+pub async fn toggle_checklist_item(
+    spec_path: String,
+    task_id: String,
+    index: usize,
+    done: Option<bool>,
+) -> Result<TaskRecord, String> {
+    let spec_dir = Path::new(&spec_path);
+    let mut tasks_file = load_tasks_file(spec_dir)?;
+    
+    let task = tasks_file.tasks.iter_mut()
+        .find(|task| task.id == task_id)
+        .ok_or_else(|| format!("Task '{}' not found", task_id))?;
+    let field = CHECKLIST_FIELDS.iter()
+        .find(|field| task.extra.get(**field).is_some_and(Value::is_array))
+        .ok_or_else(|| format!("Task '{}' has no checklist", task_id))?;
+    let items = task.extra.get_mut(*field)
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| format!("Task '{}' checklist is not a list", task_id))?;
+    
+    // `index` counts the items `parse_checklist` shows, which leaves out
+    // entries without text
+    let count = items.iter().filter(|item| checklist_item(item).is_some()).count();
+    let item = items.iter_mut()
+        .filter(|item| checklist_item(item).is_some())
+        .nth(index)
+        .ok_or_else(|| format!("Task '{}' has {} checklist items, no item {}", task_id, count, index))?;
+    
+    let done = done.unwrap_or_else(|| !checklist_item(item).is_some_and(|item| item.done));
+    if !set_checklist_item_done(item, done) {
+        return Err(format!("Checklist item {} of task '{}' is not a string or object", index, task_id));
+    }
+    
+    let task = task.clone();
+    save_tasks_file(spec_dir, &tasks_file)?;
+    Ok(task)
+}
+
+// Unticks every entry of a task's checklist, e.g. for a copy of the task
+pub(crate) fn reset_checklist(extra: &mut Map<String, Value>) {
+    let items = CHECKLIST_FIELDS.iter()
+        .find(|field| extra.get(**field).is_some_and(Value::is_array))
+        .and_then(|field| extra.get_mut(*field))
+        .and_then(Value::as_array_mut);
+    for item in items.into_iter().flatten() {
+        if checklist_item(item).is_some_and(|item| item.done) {
+            set_checklist_item_done(item, false);
+        }
+    }
+}
+
+// Returns false when the entry isn't a string or object
+fn set_checklist_item_done(item: &mut Value, done: bool) -> bool {
+    match item {
+        Value::Object(entry) if entry.contains_key("status") => {
+            let status = if done { "completed" } else { "pending" };
+            entry.insert("status".to_string(), Value::String(status.to_string()));
+        }
+        Value::Object(entry) if entry.contains_key("completed") && !entry.contains_key("done") => {
+            entry.insert("completed".to_string(), Value::Bool(done));
+        }
+        Value::Object(entry) => {
+            entry.insert("done".to_string(), Value::Bool(done));
+        }
+        Value::String(text) => {
+            let mut entry = Map::new();
+            entry.insert("text".to_string(), Value::String(std::mem::take(text)));
+            entry.insert("done".to_string(), Value::Bool(done));
+            *item = Value::Object(entry);
+        }
+        _ => return false,
+    }
+    true
+}
+
+// Reads a task's `checklist` (or `subtasks`) array, skipping entries that have
+// no text
+pub(crate) fn parse_checklist(task: &Value) -> Vec<ChecklistItem> {
+    CHECKLIST_FIELDS.iter()
+        .find_map(|field| task[*field].as_array())
+        .map(|items| items.iter().filter_map(checklist_item).collect())
+        .unwrap_or_default()
+}
+
+fn checklist_item(item: &Value) -> Option<ChecklistItem> {
+    match item {
+        Value::String(text) => Some(ChecklistItem { text: text.clone(), done: false }),
+        Value::Object(entry) => {
+            let text = ["text", "name", "title"].iter().find_map(|key| entry.get(*key)?.as_str())?;
+            let done = entry.get("done").or_else(|| entry.get("completed")).and_then(Value::as_bool)
+                .or_else(|| entry.get("status").and_then(Value::as_str).map(|status| matches!(status, "completed" | "done")))
+                .unwrap_or(false);
+            Some(ChecklistItem { text: text.to_string(), done })
+        }
+        _ => None,
+    }
+}
+
+// Share of a task that is done: 1 when completed, otherwise the share of its
+// checklist that is ticked
+pub(crate) fn task_progress(status: &str, checklist: &[ChecklistItem]) -> f64 {
+    if status == "completed" {
+        1.0
+    } else if checklist.is_empty() {
+        0.0
+    } else {
+        checklist.iter().filter(|item| item.done).count() as f64 / checklist.len() as f64
+    }
+}
+
+// Saves unless the change introduced validation errors. Problems the file
+// already had don't block unrelated edits.
+fn save_if_valid(spec_dir: &Path, tasks_file: &mut TasksFile, before: &[String]) -> Result<(), String> {
+    let new_errors: Vec<String> = validate_tasks(&tasks_file.tasks)
+        .into_iter()
+        .filter(|error| !before.contains(error))
+        .collect();
+    if !new_errors.is_empty() {
+        return Err(new_errors.join("\n"));
+    }
+    
+    save_tasks_file(spec_dir, tasks_file)
+}
+
+// Continues the numbering of the spec's most common id prefix, keeping its zero
+// padding; specs without tasks start at TASK-001
+pub(crate) fn next_task_id(tasks: &[TaskRecord]) -> String {
+    let mut prefixes: HashMap<&str, (usize, u64, usize)> = HashMap::new();
+    for task in tasks {
+        let digits = task.id.len() - task.id.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            continue;
+        }
+        let (prefix, number) = task.id.split_at(task.id.len() - digits);
+        let entry = prefixes.entry(prefix).or_insert((0, 0, digits));
+        entry.0 += 1;
+        entry.1 = entry.1.max(number.parse().unwrap_or(0));
+        entry.2 = entry.2.max(digits);
+    }
+    
+    let (prefix, (_, max, width)) = prefixes.into_iter()
+        .max_by(|a, b| a.1.0.cmp(&b.1.0).then_with(|| b.0.cmp(a.0)))
+        .unwrap_or(("TASK-", (0, 0, 3)));
+    let mut number = max + 1;
+    
+    // Skip over ids taken by tasks outside the sequence
+    while tasks.iter().any(|task| task.id == format!("{}{:0width$}", prefix, number, width = width)) {
+        number += 1;
+    }
+    format!("{}{:0width$}", prefix, number, width = width)
+}
+
+fn all_completed(tasks: &[TaskRecord]) -> bool {
+    !tasks.is_empty() && tasks.iter().all(|task| task.status == "completed")
+}
+
+fn default_task_status() -> String {
+    "pending".to_string()
+}
+
+pub(crate) fn load_tasks_file(spec_dir: &Path) -> Result<TasksFile, String> {
+    let tasks_path = spec_dir.join("tasks.json");
+    if !tasks_path.exists() {
+        return Err("tasks.json not found".to_string());
+    }
+    
+    let content = fs::read_to_string(&tasks_path)
+        .map_err(|e| format!("Failed to read tasks.json: {}", e))?;
+    let document: Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse tasks.json: {}", e))?;
+    let mut tasks_file: TasksFile = serde_json::from_value(document.clone())
+        .map_err(|e| format!("Failed to parse tasks.json: {}", e))?;
+    
+    let raw_tasks = document["tasks"].as_array().map(Vec::as_slice).unwrap_or_default();
+    tasks_file.tasks = std::mem::take(&mut tasks_file.tasks).into_iter()
+        .zip(raw_tasks)
+        .map(|(task, raw)| task.with_present_fields(raw))
+        .collect();
+    Ok(tasks_file)
+}
+
+pub(crate) fn save_tasks_file(spec_dir: &Path, tasks_file: &TasksFile) -> Result<(), String> {
+    write_file_atomic(&spec_dir.join("tasks.json"), &tasks_file_json(tasks_file)?)
+}
+
+pub(crate) fn tasks_file_json(tasks_file: &TasksFile) -> Result<String, String> {
+    serde_json::to_string_pretty(tasks_file)
+        .map_err(|e| format!("Failed to serialize tasks.json: {}", e))
+}
+
+// Applies field values on top of an existing task, or on top of an empty task
+// when creating one. A null value removes the field; fields not mentioned,
+// including extra ones, are kept.
+pub(crate) fn merge_task_fields(existing: Option<&TaskRecord>, fields: &Map<String, Value>) -> Result<TaskRecord, String> {
+    let mut document = match existing {
+        Some(task) => match serde_json::to_value(task) {
+            Ok(Value::Object(document)) => document,
+            _ => return Err("Failed to serialize existing task".to_string()),
+        },
+        None => Map::new(),
+    };
+    
+    for (field, value) in fields {
+        // Imported spreadsheet headers tend to be capitalized; reuse an existing key that differs only in case
+        let field = document.keys()
+            .find(|key| key.eq_ignore_ascii_case(field))
+            .cloned()
+            .unwrap_or_else(|| field.clone());
+        
+        if value.is_null() {
+            document.remove(&field);
+        } else {
+            document.insert(field, value.clone());
+        }
+    }
+    
+    let document = Value::Object(document);
+    serde_json::from_value::<TaskRecord>(document.clone())
+        .map(|task| task.with_present_fields(&document))
+        .map_err(|e| format!("Invalid task: {}", e))
+}
+
+// Checks ids, statuses and dependencies across a whole task list, returning
+// every problem found rather than stopping at the first
+pub(crate) fn validate_tasks(tasks: &[TaskRecord]) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut ids = HashSet::new();
+    
+    for task in tasks {
+        if task.id.trim().is_empty() {
+            errors.push(format!("Task '{}' has no id", task.name));
+        } else if !ids.insert(task.id.as_str()) {
+            errors.push(format!("Duplicate task id '{}'", task.id));
+        }
+        
+        if !TASK_STATUSES.contains(&task.status.as_str()) {
+            errors.push(format!(
+                "Task '{}' has unknown status '{}' (expected one of {})",
+                task.id,
+                task.status,
+                TASK_STATUSES.join(", ")
+            ));
+        }
+    }
+    
+    for task in tasks {
+        for dep in &task.dependencies {
+            // Tasks in other specs are resolved against the whole project by the dependency graph
+            if let (Some(spec), task_id) = split_dependency(dep) {
+                if spec.is_empty() || task_id.is_empty() {
+                    errors.push(format!("Task '{}' has malformed dependency '{}' (expected spec/TASK-ID)", task.id, dep));
+                }
+            } else if dep == &task.id {
+                errors.push(format!("Task '{}' depends on itself", task.id));
+            } else if !ids.contains(dep.as_str()) {
+                errors.push(format!("Task '{}' depends on unknown task '{}'", task.id, dep));
+            }
+        }
+    }
+    
+    if let Some(cycle) = find_dependency_cycle(tasks) {
+        errors.push(format!("Circular dependency: {}", cycle.join(" -> ")));
+    }
+    
+    errors
+}
+
+// Returns the ids along one dependency cycle, if there is any
+pub(crate) fn find_dependency_cycle(tasks: &[TaskRecord]) -> Option<Vec<String>> {
+    let deps: HashMap<&str, &Vec<String>> = tasks.iter()
+        .map(|task| (task.id.as_str(), &task.dependencies))
+        .collect();
+    
+    // 0 = unvisited, 1 = on the current path, 2 = done
+    let mut state: HashMap<&str, u8> = HashMap::new();
+    let mut path: Vec<&str> = Vec::new();
+    
+    fn visit<'a>(
+        id: &'a str,
+        deps: &HashMap<&'a str, &'a Vec<String>>,
+        state: &mut HashMap<&'a str, u8>,
+        path: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        match state.get(id) {
+            Some(2) => return None,
+            Some(1) => {
+                let start = path.iter().position(|p| *p == id).unwrap_or(0);
+                let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+                cycle.push(id.to_string());
+                return Some(cycle);
+            }
+            _ => {}
+        }
+        
+        state.insert(id, 1);
+        path.push(id);
+        for dep in deps.get(id).into_iter().flat_map(|d| d.iter()) {
+            if deps.contains_key(dep.as_str()) {
+                if let Some(cycle) = visit(dep.as_str(), deps, state, path) {
+                    return Some(cycle);
+                }
+            }
+        }
+        path.pop();
+        state.insert(id, 2);
+        None
+    }
+    
+    for task in tasks {
+        if let Some(cycle) = visit(task.id.as_str(), &deps, &mut state, &mut path) {
+            return Some(cycle);
+        }
+    }
+    
+    None
+}
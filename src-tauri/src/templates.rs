@@ -0,0 +1,560 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::tasks::TasksFile;
+
+// Project templates live in `.agent-sdd/templates/<name>/` as an `sdd.md` and a
+// `tasks.json`. Either file can be left out to use the built-in one.
+const TEMPLATES_DIR: &str = "templates";
+const SDD_TEMPLATE: &str = "sdd.md";
+const TASKS_TEMPLATE: &str = "tasks.json";
+
+pub(crate) const DEFAULT_TEMPLATE: &str = "full";
+pub(crate) const LITE_TEMPLATE: &str = "lite";
+
+// Name and sdd.md of each built-in template; they all share DEFAULT_TASKS
+const BUILTIN_TEMPLATES: [(&str, &str); 2] = [
+    (LITE_TEMPLATE, LITE_SDD),
+    (DEFAULT_TEMPLATE, FULL_SDD),
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpecTemplateInfo {
+    name: String,
+    description: String,
+    // "project" for templates in .agent-sdd/templates, otherwise "builtin"
+    source: String,
+    // Files the project template provides; the others come from the built-ins
+    files: Vec<String>,
+    path: Option<String>,
+}
+
+pub(crate) struct SpecTemplate {
+    name: String,
+    sdd: String,
+    tasks: String,
+}
+
+// Built-in templates, then the project's own. A project template with a
+// built-in's name replaces it.
+#[tauri::command]
+pub async fn list_spec_templates(project_path: String) -> Result<Vec<SpecTemplateInfo>, String> {
+    let mut templates: Vec<SpecTemplateInfo> = BUILTIN_TEMPLATES.iter()
+        .map(|(name, sdd)| SpecTemplateInfo {
+            name: name.to_string(),
+            description: template_description(sdd).unwrap_or_default(),
+            source: "builtin".to_string(),
+            files: Vec::new(),
+            path: None,
+        })
+        .collect();
+    
+    let templates_dir = templates_dir(Path::new(&project_path));
+    let entries = match fs::read_dir(&templates_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(templates),
+    };
+    
+    let mut project_templates = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) if path.is_dir() && !name.starts_with('.') => name.to_string(),
+            _ => continue,
+        };
+        let files: Vec<String> = [SDD_TEMPLATE, TASKS_TEMPLATE].iter()
+            .filter(|file| path.join(file).is_file())
+            .map(|file| file.to_string())
+            .collect();
+        if files.is_empty() {
+            continue;
+        }
+        
+        let description = fs::read_to_string(path.join(SDD_TEMPLATE)).ok()
+            .and_then(|sdd| template_description(&sdd))
+            .unwrap_or_else(|| "Project template".to_string());
+        project_templates.push(SpecTemplateInfo {
+            name,
+            description,
+            source: "project".to_string(),
+            files,
+            path: Some(path.to_string_lossy().to_string()),
+        });
+    }
+    project_templates.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    templates.retain(|builtin| !project_templates.iter().any(|template| template.name == builtin.name));
+    templates.extend(project_templates);
+    Ok(templates)
+}
+
+fn templates_dir(project_dir: &Path) -> PathBuf {
+    project_dir.join(".agent-sdd").join(TEMPLATES_DIR)
+}
+
+// A template describes itself with a `{{! ... }}` comment on its first line
+fn template_description(source: &str) -> Option<String> {
+    let first = source.lines().next()?.trim();
+    let comment = first.strip_prefix("{{!")?.strip_suffix("}}")?.trim();
+    (!comment.is_empty()).then(|| comment.to_string())
+}
+
+// Loads a template by name from the project, filling in whatever it doesn't
+// provide from the built-in of the same name, or the default template
+pub(crate) fn load_spec_template(project_dir: &Path, name: &str) -> Result<SpecTemplate, String> {
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err(format!("Invalid template name '{}'", name));
+    }
+    
+    let dir = templates_dir(project_dir).join(name);
+    let builtin = BUILTIN_TEMPLATES.iter().find(|(builtin, _)| *builtin == name);
+    if !dir.is_dir() && builtin.is_none() {
+        return Err(format!("Unknown spec template '{}'", name));
+    }
+    
+    let read = |file: &str| -> Result<Option<String>, String> {
+        let path = dir.join(file);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read template {}: {}", path.display(), e))
+    };
+    
+    Ok(SpecTemplate {
+        name: name.to_string(),
+        sdd: read(SDD_TEMPLATE)?.unwrap_or_else(|| builtin.map_or(FULL_SDD, |(_, sdd)| *sdd).to_string()),
+        tasks: read(TASKS_TEMPLATE)?.unwrap_or_else(|| DEFAULT_TASKS.to_string()),
+    })
+}
+
+impl SpecTemplate {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+    
+    pub(crate) fn render_tasks(&self, context: &Value) -> Result<TasksFile, String> {
+        let mut tasks: Value = serde_json::from_str(&self.tasks)
+            .map_err(|e| format!("Template '{}' has an invalid tasks.json: {}", self.name, e))?;
+        render_json_strings(&mut tasks, context)
+            .map_err(|e| format!("Template '{}' tasks.json: {}", self.name, e))?;
+        serde_json::from_value(tasks)
+            .map_err(|e| format!("Template '{}' produced an invalid tasks.json: {}", self.name, e))
+    }
+    
+    // Rendered after tasks.json so sdd.md can list the tasks the spec ended up with
+    pub(crate) fn render_sdd(&self, context: &Value, tasks_file: &TasksFile) -> Result<String, String> {
+        let mut context = context.clone();
+        if let Value::Object(fields) = &mut context {
+            let tasks = serde_json::to_value(&tasks_file.tasks)
+                .map_err(|e| format!("Failed to serialize tasks: {}", e))?;
+            fields.insert("tasks".to_string(), tasks);
+        }
+        render_template(&self.sdd, &context).map_err(|e| format!("Template '{}' sdd.md: {}", self.name, e))
+    }
+}
+
+// Runs every string in a JSON document through the template engine, so values
+// are escaped by serde when the document is written rather than by the template
+fn render_json_strings(value: &mut Value, context: &Value) -> Result<(), String> {
+    match value {
+        Value::String(text) if text.contains("{{") => *text = render_template(text, context)?,
+        Value::Array(items) => {
+            for item in items {
+                render_json_strings(item, context)?;
+            }
+        }
+        Value::Object(fields) => {
+            for item in fields.values_mut() {
+                render_json_strings(item, context)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// A small Handlebars-like language:
+//   {{name}}                          value of a variable, `a.b` for nested fields
+//   {{#if name}}...{{else}}...{{/if}}  also {{#unless}}; empty lists and strings are false
+//   {{#each list}}...{{/each}}        `this`, the item's fields, @index and @number (from 1);
+//                                     an {{else}} part renders when the list is empty
+//   {{! comment }}
+// Block tags alone on a line take the line with them.
+pub(crate) fn render_template(source: &str, context: &Value) -> Result<String, String> {
+    let tokens = tokenize(&strip_standalone_lines(source))?;
+    let mut tokens = tokens.into_iter();
+    let (nodes, _) = parse_nodes(&mut tokens, None)?;
+    
+    let mut out = String::new();
+    render_nodes(&nodes, &mut vec![Scope { value: context, index: None }], &mut out);
+    Ok(out)
+}
+
+enum Token {
+    Text(String),
+    Tag(String),
+}
+
+enum Node {
+    Text(String),
+    Var(String),
+    If { condition: String, negate: bool, then: Vec<Node>, otherwise: Vec<Node> },
+    Each { list: String, body: Vec<Node>, otherwise: Vec<Node> },
+}
+
+struct Scope<'a> {
+    value: &'a Value,
+    // Position in the enclosing {{#each}}
+    index: Option<usize>,
+}
+
+fn is_block_tag(tag: &str) -> bool {
+    tag.starts_with(['#', '/', '!']) || tag == "else"
+}
+
+fn strip_standalone_lines(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let standalone = trimmed.len() > 4
+            && trimmed.starts_with("{{")
+            && trimmed.ends_with("}}")
+            && trimmed.matches("{{").count() == 1
+            && is_block_tag(trimmed[2..trimmed.len() - 2].trim());
+        out.push_str(if standalone { trimmed } else { line });
+    }
+    out
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        let end = rest[start..].find("}}")
+            .ok_or_else(|| format!("Unclosed tag '{}'", rest[start..].lines().next().unwrap_or("")))?;
+        tokens.push(Token::Tag(rest[start + 2..start + end].trim().to_string()));
+        rest = &rest[start + end + 2..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    
+    Ok(tokens)
+}
+
+// Parses up to the end of the enclosing block, returning the nodes before and
+// after its {{else}}
+fn parse_nodes(tokens: &mut std::vec::IntoIter<Token>, block: Option<(&str, &str)>) -> Result<(Vec<Node>, Vec<Node>), String> {
+    let mut nodes = Vec::new();
+    let mut before_else: Option<Vec<Node>> = None;
+    
+    while let Some(token) = tokens.next() {
+        let tag = match token {
+            Token::Text(text) => {
+                nodes.push(Node::Text(text));
+                continue;
+            }
+            Token::Tag(tag) => tag,
+        };
+        
+        if tag.starts_with('!') {
+            continue;
+        }
+        if tag == "else" {
+            if block.is_none() || before_else.is_some() {
+                return Err("Unexpected 'else'".to_string());
+            }
+            before_else = Some(std::mem::take(&mut nodes));
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            return match block {
+                Some((kind, _)) if kind == name.trim() => Ok(match before_else {
+                    Some(then) => (then, nodes),
+                    None => (nodes, Vec::new()),
+                }),
+                _ => Err(format!("Unexpected '/{}'", name.trim())),
+            };
+        }
+        if let Some(opening) = tag.strip_prefix('#') {
+            let (kind, arg) = opening.split_once(char::is_whitespace)
+                .map(|(kind, arg)| (kind, arg.trim()))
+                .unwrap_or((opening, ""));
+            if !matches!(kind, "if" | "unless" | "each") {
+                return Err(format!("Unknown block '#{}'", kind));
+            }
+            if arg.is_empty() {
+                return Err(format!("'#{}' needs a variable", kind));
+            }
+            
+            let (body, otherwise) = parse_nodes(tokens, Some((kind, arg)))?;
+            nodes.push(match kind {
+                "each" => Node::Each { list: arg.to_string(), body, otherwise },
+                _ => Node::If { condition: arg.to_string(), negate: kind == "unless", then: body, otherwise },
+            });
+            continue;
+        }
+        
+        nodes.push(Node::Var(tag));
+    }
+    
+    match block {
+        Some((kind, arg)) => Err(format!("'#{} {}' is never closed", kind, arg)),
+        None => Ok((nodes, Vec::new())),
+    }
+}
+
+fn render_nodes<'a>(nodes: &[Node], scopes: &mut Vec<Scope<'a>>, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(name) => {
+                if let Some(value) = lookup(name, scopes) {
+                    out.push_str(&display(&value));
+                }
+            }
+            Node::If { condition, negate, then, otherwise } => {
+                let holds = lookup(condition, scopes).is_some_and(|value| truthy(&value)) != *negate;
+                render_nodes(if holds { then } else { otherwise }, scopes, out);
+            }
+            Node::Each { list, body, otherwise } => {
+                let items = match lookup_ref(list, scopes) {
+                    Some(Value::Array(items)) if !items.is_empty() => items,
+                    _ => {
+                        render_nodes(otherwise, scopes, out);
+                        continue;
+                    }
+                };
+                for (index, item) in items.iter().enumerate() {
+                    scopes.push(Scope { value: item, index: Some(index) });
+                    render_nodes(body, scopes, out);
+                    scopes.pop();
+                }
+            }
+        }
+    }
+}
+
+fn lookup(name: &str, scopes: &[Scope]) -> Option<Value> {
+    let index = || scopes.iter().rev().find_map(|scope| scope.index);
+    match name {
+        "@index" => index().map(Value::from),
+        "@number" => index().map(|index| Value::from(index + 1)),
+        _ => lookup_ref(name, scopes).cloned(),
+    }
+}
+
+// Looks a dotted path up in the innermost scope that has its first part;
+// `this` is the innermost scope itself
+fn lookup_ref<'a>(name: &str, scopes: &[Scope<'a>]) -> Option<&'a Value> {
+    let innermost = scopes.last()?.value;
+    if name == "this" || name == "." {
+        return Some(innermost);
+    }
+    
+    let (path, candidates): (&str, Vec<&'a Value>) = match name.strip_prefix("this.") {
+        Some(path) => (path, vec![innermost]),
+        None => (name, scopes.iter().rev().map(|scope| scope.value).collect()),
+    };
+    let mut parts = path.split('.');
+    let first = parts.next()?;
+    let start = candidates.into_iter().find_map(|value| value.get(first))?;
+    parts.try_fold(start, |value, part| value.get(part))
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64() != Some(0.0),
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(fields) => !fields.is_empty(),
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+const LITE_SDD: &str = r#"{{! Minimal spec: goal, success criteria and milestones }}
+# {{spec_name}}
+
+## Overview
+
+**Goal:** {{description}}
+
+**User Story:** {{#if user_story}}{{user_story}}{{else}}As a user, I want {{description}} so that I can achieve my objectives effectively.{{/if}}
+
+**Success Criteria:**
+{{#each acceptance_criteria}}
+- [ ] {{this}}
+{{else}}
+- [ ] Core functionality is implemented
+- [ ] User interface is intuitive and responsive
+- [ ] All acceptance tests pass
+{{/each}}
+
+## Tasks
+
+Tasks are defined in tasks.json with detailed breakdown, dependencies, and effort estimates.
+
+Key milestones:
+{{#each tasks}}
+{{@number}}. {{name}}
+{{/each}}
+
+{{#each sections}}
+## {{title}}
+
+{{body}}
+
+{{/each}}
+## Next Steps
+
+Review tasks.json for detailed task breakdown and begin with the first pending task.
+"#;
+
+const FULL_SDD: &str = r#"{{! Full spec with UI requirements, theme compliance and test scenarios }}
+# {{spec_name}}
+
+## Overview
+
+**Goal:** {{description}}
+
+**User Story:** {{#if user_story}}{{user_story}}{{else}}As a user, I want {{description}} so that I can achieve my objectives effectively.{{/if}}
+
+**Success Criteria:**
+{{#each acceptance_criteria}}
+- [ ] {{this}}
+{{else}}
+- [ ] Core functionality is implemented
+- [ ] User interface meets design requirements
+- [ ] Performance meets specified benchmarks
+- [ ] All acceptance tests pass
+- [ ] Documentation is complete
+{{/each}}
+
+## Technical Specifications
+
+### UI Requirements
+
+**Layout:**
+- Clean, intuitive interface following established design patterns
+- Responsive design supporting desktop and mobile viewports
+- Consistent styling with theme standards
+
+**Components:**
+- Main interface components with clear hierarchy
+- Interactive elements with appropriate feedback
+- Error handling and loading states
+
+**User Experience:**
+- Intuitive navigation and workflow
+- Clear call-to-action buttons
+- Helpful error messages and guidance
+
+### Theme Standards Compliance
+
+This feature will adhere to the theme standards defined in .agent-sdd/standards/theme-standards.md:
+- Color palette consistency
+- Typography standards
+- Spacing and layout guidelines
+- Accessibility requirements
+
+## Tasks
+
+Detailed task breakdown is provided in tasks.json including:
+- Task IDs and dependencies
+- Effort estimates (XS=1 day, S=2-3 days, M=1 week)
+- Implementation order and prerequisites
+{{#if tasks}}
+
+| ID | Task | Effort | Depends on |
+|---|---|---|---|
+{{#each tasks}}
+| {{id}} | {{name}} | {{effort}} | {{#if dependencies}}{{dependencies}}{{else}}-{{/if}} |
+{{/each}}
+{{/if}}
+
+## Test Scenarios
+
+**Unit Tests:**
+- [ ] Core logic functions
+- [ ] Edge cases and error conditions
+- [ ] Input validation
+
+**Integration Tests:**
+- [ ] Component interactions
+- [ ] API integrations
+- [ ] Database operations
+
+**User Acceptance Tests:**
+- [ ] End-to-end user workflows
+- [ ] Cross-browser compatibility
+- [ ] Accessibility compliance
+
+{{#each sections}}
+## {{title}}
+
+{{body}}
+
+{{/each}}
+## Implementation Notes
+
+Additional technical details and decisions will be documented as implementation progresses.
+"#;
+
+const DEFAULT_TASKS: &str = r#"{
+  "phase": "Phase 1",
+  "feature": "{{spec_name}}",
+  "status": "planned",
+  "created": "{{date}}",
+  "tasks": [
+    {
+      "id": "TASK-001",
+      "name": "Setup component structure",
+      "description": "Create main component structure and basic layout",
+      "status": "pending",
+      "dependencies": [],
+      "effort": "XS"
+    },
+    {
+      "id": "TASK-002",
+      "name": "Implement core functionality",
+      "description": "Add primary business logic and core features",
+      "status": "pending",
+      "dependencies": ["TASK-001"],
+      "effort": "M"
+    },
+    {
+      "id": "TASK-003",
+      "name": "Add user interface",
+      "description": "Create user interface components and styling",
+      "status": "pending",
+      "dependencies": ["TASK-001"],
+      "effort": "S"
+    },
+    {
+      "id": "TASK-004",
+      "name": "Integration testing",
+      "description": "Test component integration and user workflows",
+      "status": "pending",
+      "dependencies": ["TASK-002", "TASK-003"],
+      "effort": "S"
+    }
+  ]
+}"#;
@@ -0,0 +1,383 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager};
+use tokio::task::JoinSet;
+
+use crate::commands::{calculate_directory_stats, list_child_directories, scan_specs, DirectoryInfo, SpecMetadata};
+use crate::query::TaskQueryItem;
+
+const WORKSPACE_CACHE_FILE: &str = "workspace-cache.json";
+const PROJECT_DISCOVERED_EVENT: &str = "workspace://project-discovered";
+const DEFAULT_DISCOVERY_DEPTH: usize = 4;
+const SKIPPED_DIRECTORIES: [&str; 2] = ["node_modules", "target"];
+
+// Scanned specs per project, reused while none of the project's spec directories change
+#[derive(Default)]
+pub struct WorkspaceCache {
+    projects: Mutex<HashMap<String, CachedProject>>,
+    loaded: Mutex<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedProject {
+    fingerprint: Vec<SpecFingerprint>,
+    specs: Vec<SpecMetadata>,
+}
+
+// Identifies the state of one spec directory without parsing its tasks.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SpecFingerprint {
+    dir_name: String,
+    size: u64,
+    mtime: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveredProject {
+    name: String,
+    full_path: String,
+    // Location relative to the base directory, e.g. `apps/web`, or `.` for the base itself
+    rel_path: String,
+    depth: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ProjectDiscoveredEvent {
+    base_path: String,
+    project: DiscoveredProject,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceReport {
+    base_path: String,
+    projects: Vec<ProjectSummary>,
+    totals: WorkspaceTotals,
+    tasks: Vec<TaskQueryItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    name: String,
+    path: String,
+    spec_count: usize,
+    task_count: usize,
+    tasks_by_status: BTreeMap<String, usize>,
+    last_activity: Option<u64>,
+    from_cache: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WorkspaceTotals {
+    project_count: usize,
+    spec_count: usize,
+    task_count: usize,
+    tasks_by_status: BTreeMap<String, usize>,
+    last_activity: Option<u64>,
+}
+
+#[tauri::command]
+pub async fn scan_workspace(
+    app: tauri::AppHandle,
+    cache: tauri::State<'_, WorkspaceCache>,
+    base_path: String,
+    max_depth: Option<usize>,
+    force_refresh: Option<bool>,
+) -> Result<WorkspaceReport, String> {
+    // Without a depth only direct children are considered, as in list_child_directories
+    let directories = match max_depth {
+        Some(max_depth) => {
+            let base = base_path.clone();
+            tokio::task::spawn_blocking(move || discover_agent_sdd_projects(Path::new(&base), max_depth, |_| {}))
+                .await
+                .map_err(|e| format!("Project discovery failed: {}", e))??
+                .into_iter()
+                .map(|project| DirectoryInfo {
+                    name: project.rel_path,
+                    full_path: project.full_path,
+                })
+                .collect()
+        }
+        None => list_child_directories(base_path.clone()).await?,
+    };
+    let cache_file = app.path().app_cache_dir().ok().map(|dir| dir.join(WORKSPACE_CACHE_FILE));
+    let force_refresh = force_refresh.unwrap_or(false);
+    
+    cache.load_once(cache_file.as_deref());
+    
+    let mut scans = JoinSet::new();
+    for (order, directory) in directories.into_iter().enumerate() {
+        let cached = if force_refresh { None } else { cache.get(&directory.full_path) };
+        
+        scans.spawn(async move {
+            let fingerprint = spec_fingerprint(Path::new(&directory.full_path));
+            let result = match cached {
+                Some(cached) if cached.fingerprint == fingerprint => Ok((cached.specs, true)),
+                _ => scan_specs(directory.full_path.clone(), None).await.map(|specs| (specs, false)),
+            };
+            (order, directory, fingerprint, result)
+        });
+    }
+    
+    let mut scanned = Vec::new();
+    while let Some(joined) = scans.join_next().await {
+        match joined {
+            Ok(result) => scanned.push(result),
+            Err(e) => log::warn!("Workspace scan task failed: {}", e),
+        }
+    }
+    scanned.sort_by_key(|(order, ..)| *order);
+    
+    let mut projects = Vec::new();
+    let mut tasks = Vec::new();
+    let mut totals = WorkspaceTotals::default();
+    let mut cache_changed = false;
+    
+    for (_, directory, fingerprint, result) in scanned {
+        let (specs, from_cache) = match result {
+            Ok(result) => result,
+            Err(e) => {
+                log::warn!("Failed to scan project {}: {}", directory.full_path, e);
+                projects.push(ProjectSummary {
+                    name: directory.name,
+                    path: directory.full_path,
+                    spec_count: 0,
+                    task_count: 0,
+                    tasks_by_status: BTreeMap::new(),
+                    last_activity: None,
+                    from_cache: false,
+                    error: Some(e),
+                });
+                continue;
+            }
+        };
+        
+        let mut tasks_by_status = BTreeMap::new();
+        let mut task_count = 0;
+        for spec in &specs {
+            for task in &spec.tasks {
+                *tasks_by_status.entry(task.status.clone()).or_insert(0) += 1;
+                task_count += 1;
+                tasks.push(TaskQueryItem::new(&directory.name, &directory.full_path, spec, task));
+            }
+        }
+        let last_activity = specs.iter().filter_map(|spec| spec.last_modified).max();
+        
+        totals.project_count += 1;
+        totals.spec_count += specs.len();
+        totals.task_count += task_count;
+        totals.last_activity = totals.last_activity.max(last_activity);
+        for (status, count) in &tasks_by_status {
+            *totals.tasks_by_status.entry(status.clone()).or_insert(0) += count;
+        }
+        
+        projects.push(ProjectSummary {
+            name: directory.name,
+            path: directory.full_path.clone(),
+            spec_count: specs.len(),
+            task_count,
+            tasks_by_status,
+            last_activity,
+            from_cache,
+            error: None,
+        });
+        
+        if !from_cache {
+            cache.insert(directory.full_path, CachedProject { fingerprint, specs });
+            cache_changed = true;
+        }
+    }
+    
+    if cache_changed {
+        if let Some(cache_file) = &cache_file {
+            if let Err(e) = cache.save(cache_file) {
+                log::warn!("Failed to persist workspace cache: {}", e);
+            }
+        }
+    }
+    
+    Ok(WorkspaceReport {
+        base_path,
+        projects,
+        totals,
+        tasks,
+    })
+}
+
+// Walks the base directory looking for nested `.agent-sdd` projects, emitting an
+// event for each one as it is found so the UI can fill in while large trees are walked
+#[tauri::command]
+pub async fn discover_projects(
+    app: tauri::AppHandle,
+    base_path: String,
+    max_depth: Option<usize>,
+) -> Result<Vec<DiscoveredProject>, String> {
+    let max_depth = max_depth.unwrap_or(DEFAULT_DISCOVERY_DEPTH);
+    
+    tokio::task::spawn_blocking(move || {
+        discover_agent_sdd_projects(Path::new(&base_path), max_depth, |project| {
+            let event = ProjectDiscoveredEvent {
+                base_path: base_path.clone(),
+                project: project.clone(),
+            };
+            if let Err(e) = app.emit(PROJECT_DISCOVERED_EVENT, event) {
+                log::warn!("Failed to emit discovered project {}: {}", project.full_path, e);
+            }
+        })
+    })
+    .await
+    .map_err(|e| format!("Project discovery failed: {}", e))?
+}
+
+pub(crate) fn discover_agent_sdd_projects(
+    base: &Path,
+    max_depth: usize,
+    mut on_found: impl FnMut(&DiscoveredProject),
+) -> Result<Vec<DiscoveredProject>, String> {
+    if !base.exists() || !base.is_dir() {
+        return Err("Base path does not exist or is not a directory".to_string());
+    }
+    
+    // Hidden directories are not descended into, but `.agent-sdd` is checked for
+    // explicitly on every visited directory. Following symlinks lets the walker
+    // detect loops, which surface as errors and are skipped.
+    let walker = WalkBuilder::new(base)
+        .max_depth(Some(max_depth))
+        .follow_links(true)
+        .hidden(true)
+        .git_ignore(true)
+        .git_exclude(true)
+        .require_git(false)
+        .filter_entry(|entry| {
+            let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let skipped = entry.file_name()
+                .to_str()
+                .map(|name| SKIPPED_DIRECTORIES.contains(&name))
+                .unwrap_or(false);
+            is_dir && !skipped
+        })
+        .build();
+    
+    let mut projects = Vec::new();
+    let mut seen = HashSet::new();
+    
+    for result in walker {
+        let entry = match result {
+            Ok(entry) => entry,
+            Err(e) => {
+                log::warn!("Skipping entry during project discovery: {}", e);
+                continue;
+            }
+        };
+        
+        let path = entry.path();
+        if !path.join(".agent-sdd").is_dir() {
+            continue;
+        }
+        
+        // The same project can be reachable through more than one symlink
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if !seen.insert(canonical) {
+            continue;
+        }
+        
+        let rel_path = path.strip_prefix(base)
+            .map(|rel| rel.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let name = path.file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&rel_path)
+            .to_string();
+        
+        let project = DiscoveredProject {
+            name,
+            full_path: path.to_string_lossy().to_string(),
+            rel_path: if rel_path.is_empty() { ".".to_string() } else { rel_path },
+            depth: entry.depth(),
+        };
+        on_found(&project);
+        projects.push(project);
+    }
+    
+    projects.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+    Ok(projects)
+}
+
+impl WorkspaceCache {
+    // Seeds the in-memory cache from disk the first time a workspace is scanned,
+    // so reopening the app doesn't re-parse every project
+    fn load_once(&self, cache_file: Option<&Path>) {
+        let mut loaded = match self.loaded.lock() {
+            Ok(loaded) => loaded,
+            Err(_) => return,
+        };
+        if *loaded {
+            return;
+        }
+        *loaded = true;
+        
+        let stored = cache_file
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<HashMap<String, CachedProject>>(&content).ok());
+        
+        if let (Some(stored), Ok(mut projects)) = (stored, self.projects.lock()) {
+            for (path, project) in stored {
+                projects.entry(path).or_insert(project);
+            }
+        }
+    }
+    
+    fn get(&self, project_path: &str) -> Option<CachedProject> {
+        self.projects.lock().ok()?.get(project_path).cloned()
+    }
+    
+    fn insert(&self, project_path: String, project: CachedProject) {
+        if let Ok(mut projects) = self.projects.lock() {
+            projects.insert(project_path, project);
+        }
+    }
+    
+    fn save(&self, cache_file: &Path) -> Result<(), String> {
+        let content = {
+            let projects = self.projects.lock()
+                .map_err(|e| format!("Failed to lock workspace cache: {}", e))?;
+            serde_json::to_string(&*projects)
+                .map_err(|e| format!("Failed to serialize workspace cache: {}", e))?
+        };
+        
+        if let Some(parent) = cache_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+        fs::write(cache_file, content)
+            .map_err(|e| format!("Failed to write workspace cache: {}", e))
+    }
+}
+
+fn spec_fingerprint(project_dir: &Path) -> Vec<SpecFingerprint> {
+    let specs_dir = project_dir.join(".agent-sdd").join("specs");
+    let mut fingerprint = Vec::new();
+    
+    if let Ok(entries) = fs::read_dir(&specs_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.join("tasks.json").is_file() {
+                continue;
+            }
+            
+            let (size, mtime) = calculate_directory_stats(&path);
+            fingerprint.push(SpecFingerprint {
+                dir_name: entry.file_name().to_string_lossy().to_string(),
+                size,
+                mtime,
+            });
+        }
+    }
+    
+    fingerprint.sort_by(|a, b| a.dir_name.cmp(&b.dir_name));
+    fingerprint
+}